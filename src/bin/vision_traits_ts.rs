@@ -0,0 +1,39 @@
+use std::io::{self, Read};
+use std::{env, fs, process};
+use vision_traits::schema::Function;
+use vision_traits::{json, typescript, DynErrResult};
+
+// Reads a JSON array of `Function` schemas (as produced by
+// `Function::to_json`) from a file or stdin and prints TypeScript
+// declarations for them. Nodes are registered by the crates defining them,
+// so to generate declarations from the registry instead, call
+// `typescript::linked_declarations` from a binary linking those crates.
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> DynErrResult<()> {
+    let input = match env::args().nth(1) {
+        Some(ref path) if path != "-" => fs::read_to_string(path)?,
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+    };
+
+    let json = json::parse(&input)?;
+    let functions = if json.is_array() {
+        json.members()
+            .map(Function::from_json)
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        vec![Function::from_json(&json)?]
+    };
+
+    print!("{}", typescript::declarations(&functions));
+    Ok(())
+}
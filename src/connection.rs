@@ -0,0 +1,117 @@
+use crate::port::PortType;
+use crate::schema::{Function, Type};
+use std::any::Any;
+use std::collections::HashMap;
+use thiserror::Error;
+
+type ConvertFn = Box<dyn Fn(&dyn Any) -> Option<Box<dyn Any>>>;
+
+/// Conversions between port types, keyed by `PortType::id`, which the
+/// pipeline applies to edges whose output and input types differ.
+///
+/// ```
+/// # use vision_traits::connection::ConversionRegistry;
+/// let mut conversions = ConversionRegistry::new();
+/// conversions.register(|contours: &Vec<u32>| contours.first().copied());
+/// conversions.register(|value: &u8| Some(f32::from(*value) / 255.0));
+/// ```
+#[derive(Default)]
+pub struct ConversionRegistry {
+    conversions: HashMap<(String, String), ConvertFn>,
+}
+
+impl ConversionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a conversion from `A` to `B`, replacing any previous one.
+    /// Values converted to `None` leave optional inputs unconnected, and fail
+    /// the frame with `PipelineError::ConversionFailed` for required inputs.
+    pub fn register<A: PortType, B: PortType>(
+        &mut self,
+        convert: impl Fn(&A) -> Option<B> + 'static,
+    ) {
+        self.conversions.insert(
+            (A::id(), B::id()),
+            Box::new(move |value| {
+                let value = convert(value.downcast_ref::<A>()?)?;
+                Some(Box::new(value) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    pub fn contains(&self, from: &str, to: &str) -> bool {
+        self.conversions
+            .contains_key(&(from.to_owned(), to.to_owned()))
+    }
+
+    /// Whether an output of type `from` can feed an input of type `to`,
+    /// either directly or through a registered conversion.
+    pub fn compatible(&self, from: &Type, to: &Type) -> bool {
+        from.id == to.id || self.contains(&from.id, &to.id)
+    }
+
+    /// Converts `value` of the type with id `from` to the type with id `to`,
+    /// returning `None` if there is no such conversion or it failed.
+    pub fn convert(&self, from: &str, to: &str, value: &dyn Any) -> Option<Box<dyn Any>> {
+        let convert = self.conversions.get(&(from.to_owned(), to.to_owned()))?;
+        convert(value)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionError {
+    #[error("no output named `{0}`")]
+    UnknownOutput(String),
+    #[error("no input named `{0}`")]
+    UnknownInput(String),
+    #[error("{from} (`{from_id}`) cannot be converted to {to} (`{to_id}`)")]
+    Incompatible {
+        from: String,
+        from_id: String,
+        to: String,
+        to_id: String,
+    },
+}
+
+/// Checks that `output` of a node with schema `from` can feed `input` of a
+/// node with schema `to`, without running either.
+pub fn check_connection(
+    from: &Function,
+    output: &str,
+    to: &Function,
+    input: &str,
+    conversions: &ConversionRegistry,
+) -> Result<(), ConnectionError> {
+    let from = from
+        .outputs
+        .get(output)
+        .ok_or_else(|| ConnectionError::UnknownOutput(output.to_owned()))?;
+    let to =
+        input_port(to, input).ok_or_else(|| ConnectionError::UnknownInput(input.to_owned()))?;
+
+    if conversions.compatible(from, to) {
+        Ok(())
+    } else {
+        Err(ConnectionError::Incompatible {
+            from: from.name.clone(),
+            from_id: from.id.clone(),
+            to: to.name.clone(),
+            to_id: to.id.clone(),
+        })
+    }
+}
+
+/// The type of the input `name` of `function`, where the slots of a variadic
+/// input `port` are named `port.0`, `port.1`, ...
+pub fn input_port<'a>(function: &'a Function, name: &str) -> Option<&'a Type> {
+    match function.inputs.get(name) {
+        Some(ty) if !ty.variadic => Some(ty),
+        _ => {
+            let (port, index) = name.rsplit_once('.')?;
+            index.parse::<usize>().ok()?;
+            function.inputs.get(port).filter(|ty| ty.variadic)
+        }
+    }
+}
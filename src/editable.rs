@@ -1,29 +1,90 @@
-use crate::DynErrResult;
 use crate::schema::SettingType;
+use crate::types::bounds::FloatBounds;
+use crate::{DeserializationError, ErrorCollector, ValidationError};
+use json::number::Number;
 use json::JsonValue;
+use std::any::type_name;
 use std::collections::HashMap;
-use std::error::Error;
+use std::convert::{TryFrom, TryInto};
+use std::fmt::{Display, LowerExp};
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::ops::Neg;
+use std::str::FromStr;
 
 pub trait Editable: Sized + 'static {
     fn schema() -> SettingType;
-    fn deserialize(input: &JsonValue) -> DynErrResult<Self>;
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError>;
+    fn serialize(&self) -> JsonValue;
+
+    /// The value of a field left out of its object, if it may be left out.
+    fn missing() -> Option<Self> {
+        None
+    }
 }
 
 macro_rules! editable_integral {
-    ($ty:ident => $method:ident) => {
+    ($ty:ident) => {
+        impl Editable for $ty {
+            fn schema() -> SettingType {
+                let mut map = HashMap::new();
+                map.insert("min".to_owned(), integer_to_json($ty::MIN));
+                map.insert("max".to_owned(), integer_to_json($ty::MAX));
+
+                SettingType {
+                    name: stringify!($ty).to_owned(),
+                    params: map,
+                }
+            }
+            fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                bounded_integer(input, $ty::MIN, $ty::MAX, true)
+            }
+            fn serialize(&self) -> JsonValue {
+                integer_to_json(*self)
+            }
+        }
+    };
+}
+
+macro_rules! editable_nonzero {
+    ($ty:ident => $inner:ident) => {
         impl Editable for $ty {
             fn schema() -> SettingType {
                 let mut map = HashMap::new();
-                map.insert("min".to_owned(), ($ty::MIN as f64).into());
-                map.insert("max".to_owned(), ($ty::MAX as f64).into());
+                map.insert("min".to_owned(), integer_to_json($ty::MIN.get()));
+                map.insert("max".to_owned(), integer_to_json($ty::MAX.get()));
 
                 SettingType {
                     name: stringify!($ty).to_owned(),
                     params: map,
                 }
             }
-            fn deserialize(input: &JsonValue) -> DynErrResult<Self> {
-                Ok(input.$method().ok_or(concat!("input could not be deserialized into ", stringify!($ty)))?)
+            fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                Ok($ty::new($inner::deserialize(input)?).ok_or(ValidationError::Zero)?)
+            }
+            fn serialize(&self) -> JsonValue {
+                integer_to_json(self.get())
+            }
+        }
+    };
+}
+
+macro_rules! editable_float {
+    ($ty:ident) => {
+        impl Editable for $ty {
+            fn schema() -> SettingType {
+                SettingType {
+                    name: stringify!($ty).to_owned(),
+                    params: HashMap::new(),
+                }
+            }
+            fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                float_from_json(input).ok_or_else(|| DeserializationError::wrong_type(stringify!($ty)))
+            }
+            fn serialize(&self) -> JsonValue {
+                float_to_json(*self)
             }
         }
     };
@@ -38,21 +99,41 @@ macro_rules! editable {
                     params: HashMap::new(),
                 }
             }
-            fn deserialize(input: &JsonValue) -> Result<Self, Box<dyn Error>> {
-                Ok(input.$method().ok_or(concat!("input could not be deserialized into", stringify!($ty)))?)
+            fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                input.$method().ok_or_else(|| DeserializationError::wrong_type(stringify!($ty)))
+            }
+            fn serialize(&self) -> JsonValue {
+                (*self).into()
             }
         }
     };
 }
 
-editable_integral!(u8 => as_u8);
-editable_integral!(u16 => as_u16);
-editable_integral!(u32 => as_u32);
-editable_integral!(i8 => as_i8);
-editable_integral!(i16 => as_i16);
-editable_integral!(i32 => as_i32);
+editable_integral!(u8);
+editable_integral!(u16);
+editable_integral!(u32);
+editable_integral!(u64);
+editable_integral!(usize);
+editable_integral!(i8);
+editable_integral!(i16);
+editable_integral!(i32);
+editable_integral!(i64);
+editable_integral!(isize);
+
+editable_nonzero!(NonZeroU8 => u8);
+editable_nonzero!(NonZeroU16 => u16);
+editable_nonzero!(NonZeroU32 => u32);
+editable_nonzero!(NonZeroU64 => u64);
+editable_nonzero!(NonZeroUsize => usize);
+editable_nonzero!(NonZeroI8 => i8);
+editable_nonzero!(NonZeroI16 => i16);
+editable_nonzero!(NonZeroI32 => i32);
+editable_nonzero!(NonZeroI64 => i64);
+editable_nonzero!(NonZeroIsize => isize);
+
+editable_float!(f32);
+editable_float!(f64);
 
-editable!(f64 => as_f64);
 editable!(bool => as_bool);
 
 impl Editable for String {
@@ -62,7 +143,322 @@ impl Editable for String {
             params: HashMap::new(),
         }
     }
-    fn deserialize(input: &JsonValue) -> Result<Self, Box<dyn Error>> {
-        Ok(input.as_str().map(|e| e.to_owned()).unwrap())
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+        input
+            .as_str()
+            .map(|e| e.to_owned())
+            .ok_or_else(|| DeserializationError::wrong_type("string"))
+    }
+    fn serialize(&self) -> JsonValue {
+        self.as_str().into()
+    }
+}
+
+impl<T: Editable> Editable for Option<T> {
+    fn schema() -> SettingType {
+        composite("option", T::schema())
+    }
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+        if input.is_null() {
+            Ok(None)
+        } else {
+            T::deserialize(input).map(Some)
+        }
+    }
+    fn serialize(&self) -> JsonValue {
+        self.as_ref().map_or(JsonValue::Null, T::serialize)
+    }
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: Editable> Editable for Vec<T> {
+    fn schema() -> SettingType {
+        composite("list", T::schema())
+    }
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+        if !input.is_array() {
+            return Err(DeserializationError::wrong_type("list"));
+        }
+
+        let mut errors = ErrorCollector::new();
+        let elements = input
+            .members()
+            .enumerate()
+            .filter_map(|(i, e)| errors.check(T::deserialize(e).map_err(|e| e.at_index(i))))
+            .collect();
+        errors.finish().map(|()| elements)
+    }
+    fn serialize(&self) -> JsonValue {
+        JsonValue::Array(self.iter().map(T::serialize).collect())
+    }
+}
+
+impl<T: Editable, const N: usize> Editable for [T; N] {
+    fn schema() -> SettingType {
+        let mut setting = composite("array", T::schema());
+        setting.params.insert("length".to_owned(), N.into());
+        setting
+    }
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+        if !input.is_array() || input.len() != N {
+            return Err(DeserializationError::wrong_type(format!(
+                "array of length {}",
+                N
+            )));
+        }
+        let elements = Vec::<T>::deserialize(input)?;
+        Ok(<[T; N]>::try_from(elements).unwrap_or_else(|_| unreachable!()))
+    }
+    fn serialize(&self) -> JsonValue {
+        JsonValue::Array(self.iter().map(T::serialize).collect())
+    }
+}
+
+impl<T: Editable> Editable for HashMap<String, T> {
+    fn schema() -> SettingType {
+        composite("map", T::schema())
+    }
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+        if !input.is_object() {
+            return Err(DeserializationError::wrong_type("map"));
+        }
+
+        let mut errors = ErrorCollector::new();
+        let entries = input
+            .entries()
+            .filter_map(|(key, value)| {
+                let value = errors.check(T::deserialize(value).map_err(|e| e.in_field(key)))?;
+                Some((key.to_owned(), value))
+            })
+            .collect();
+        errors.finish().map(|()| entries)
+    }
+    fn serialize(&self) -> JsonValue {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut json = JsonValue::new_object();
+        for (key, value) in entries {
+            json[key.as_str()] = value.serialize();
+        }
+        json
+    }
+}
+
+macro_rules! editable_tuple {
+    ($len:expr => $($ty:ident $index:tt),+) => {
+        impl<$($ty: Editable),+> Editable for ($($ty,)+) {
+            fn schema() -> SettingType {
+                let mut map = HashMap::new();
+                map.insert("elements".to_owned(), JsonValue::Array(vec![$($ty::schema().to_json()),+]));
+
+                SettingType {
+                    name: "tuple".to_owned(),
+                    params: map,
+                }
+            }
+            fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                if !input.is_array() || input.len() != $len {
+                    return Err(DeserializationError::wrong_type(concat!("tuple of length ", $len)));
+                }
+
+                let mut errors = ErrorCollector::new();
+                let elements = ($(errors.check($ty::deserialize(&input[$index]).map_err(|e| e.at_index($index))),)+);
+                errors.finish()?;
+                Ok(($(elements.$index.unwrap(),)+))
+            }
+            fn serialize(&self) -> JsonValue {
+                JsonValue::Array(vec![$(self.$index.serialize()),+])
+            }
+        }
+    };
+}
+
+editable_tuple!(1 => A 0);
+editable_tuple!(2 => A 0, B 1);
+editable_tuple!(3 => A 0, B 1, C 2);
+editable_tuple!(4 => A 0, B 1, C 2, D 3);
+editable_tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+editable_tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+
+fn composite(name: &str, element: SettingType) -> SettingType {
+    let mut map = HashMap::new();
+    map.insert("element".to_owned(), element.to_json());
+
+    SettingType {
+        name: name.to_owned(),
+        params: map,
+    }
+}
+
+// Bounds of floating point `Constrained` and `Range` settings.
+pub(crate) fn float_bounds<T, B>() -> HashMap<String, JsonValue>
+where
+    T: LowerExp + Into<f64> + Copy,
+    B: FloatBounds<T>,
+{
+    let mut map = HashMap::new();
+    map.insert("min".to_owned(), float_to_json(B::MIN));
+    map.insert("max".to_owned(), float_to_json(B::MAX));
+    map.insert("inclusive".to_owned(), B::INCLUSIVE.into());
+    if let Some(step) = B::STEP {
+        map.insert("step".to_owned(), float_to_json(step));
+    }
+    map
+}
+
+pub(crate) fn bounded_integer<T>(
+    input: &JsonValue,
+    min: T,
+    max: T,
+    inclusive: bool,
+) -> Result<T, DeserializationError>
+where
+    T: TryFrom<i128> + TryInto<i128>,
+{
+    let value = integer_from_json::<i128>(input)
+        .ok_or_else(|| DeserializationError::wrong_type(type_name::<T>()))?;
+    let min = min.try_into().unwrap_or_else(|_| unreachable!());
+    let max = max.try_into().unwrap_or_else(|_| unreachable!());
+    check_bounds(&value, &min, &max, inclusive)?;
+    Ok(T::try_from(value).unwrap_or_else(|_| unreachable!()))
+}
+
+pub(crate) fn bounded_float<T, B>(input: &JsonValue) -> Result<T, DeserializationError>
+where
+    T: FromStr + Neg<Output = T> + Into<f64> + Copy + PartialOrd + Display,
+    B: FloatBounds<T>,
+{
+    let value = float_from_json::<T>(input)
+        .ok_or_else(|| DeserializationError::wrong_type(type_name::<T>()))?;
+    check_bounds(&value, &B::MIN, &B::MAX, B::INCLUSIVE)?;
+    Ok(value)
+}
+
+// Written so that NaN is rejected as below the minimum.
+pub(crate) fn check_bounds<T: PartialOrd + Display>(
+    value: &T,
+    min: &T,
+    max: &T,
+    inclusive: bool,
+) -> Result<(), ValidationError> {
+    let above_min = if inclusive { value >= min } else { value > min };
+    let below_max = if inclusive { value <= max } else { value < max };
+    if !above_min {
+        Err(ValidationError::BelowMinimum {
+            value: value.to_string(),
+            bound: min.to_string(),
+            inclusive,
+        })
+    } else if !below_max {
+        Err(ValidationError::AboveMaximum {
+            value: value.to_string(),
+            bound: max.to_string(),
+            inclusive,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Reads the `min` and `max` of a range setting with `read`, checking that
+/// they are ordered.
+pub(crate) fn range_bounds<T: PartialOrd + Display>(
+    input: &JsonValue,
+    read: impl Fn(&JsonValue) -> Result<T, DeserializationError>,
+) -> Result<(T, T), DeserializationError> {
+    let map = match input {
+        JsonValue::Object(map) => map,
+        _ => return Err(DeserializationError::wrong_type("object")),
+    };
+
+    let mut errors = ErrorCollector::new();
+    let mut bound = |key: &str| match map.get(key) {
+        Some(value) => errors.check(read(value).map_err(|e| e.in_field(key))),
+        None => {
+            errors.push(DeserializationError::missing(key));
+            None
+        }
+    };
+    let (min, max) = (bound("min"), bound("max"));
+    errors.finish()?;
+
+    let (min, max) = (min.unwrap(), max.unwrap());
+    check_range(&min, &max)?;
+    Ok((min, max))
+}
+
+pub(crate) fn check_range<T: PartialOrd + Display>(
+    min: &T,
+    max: &T,
+) -> Result<(), ValidationError> {
+    if min > max {
+        Err(ValidationError::InvertedRange {
+            min: min.to_string(),
+            max: max.to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// `json` stores numbers as a decimal mantissa and exponent, so integers are
+// converted through those parts directly to stay exact across the whole
+// 64-bit range.
+pub(crate) fn integer_to_json<T: TryInto<i128>>(value: T) -> JsonValue {
+    let value = value.try_into().unwrap_or_else(|_| unreachable!());
+    Number::from_parts(value >= 0, value.unsigned_abs() as u64, 0).into()
+}
+
+pub(crate) fn integer_from_json<T: TryFrom<i128>>(input: &JsonValue) -> Option<T> {
+    let (positive, mantissa, exponent) = input.as_number()?.as_parts();
+    let mut value = i128::from(mantissa);
+    if exponent >= 0 {
+        value = value.checked_mul(10i128.checked_pow(exponent as u32)?)?;
+    } else {
+        let divisor = 10i128.checked_pow(exponent.unsigned_abs() as u32)?;
+        if value % divisor != 0 {
+            return None;
+        }
+        value /= divisor;
+    }
+    T::try_from(if positive { value } else { -value }).ok()
+}
+
+// `json` converts between floats and its decimal numbers lossily, so go
+// through Rust's shortest round-trip formatting and exact parsing instead.
+pub(crate) fn float_to_json<T: LowerExp + Into<f64> + Copy>(value: T) -> JsonValue {
+    if !value.into().is_finite() {
+        return value.into().into();
+    }
+
+    let repr = format!("{:e}", value);
+    let (digits, exponent) = repr.split_at(repr.find('e').unwrap());
+    let exponent = exponent[1..].parse::<i16>().unwrap();
+    let positive = !digits.starts_with('-');
+    let digits = digits.trim_start_matches('-');
+    let fraction = digits.find('.').map_or(0, |i| digits.len() - i - 1);
+    let mantissa = digits.replace('.', "").parse::<u64>().unwrap();
+
+    Number::from_parts(positive, mantissa, exponent - fraction as i16).into()
+}
+
+// Numbers beyond the range of `T` are rejected rather than read as infinity.
+pub(crate) fn float_from_json<T>(input: &JsonValue) -> Option<T>
+where
+    T: FromStr + Neg<Output = T> + Into<f64> + Copy,
+{
+    let number = input.as_number()?;
+    if number.is_nan() {
+        return "NaN".parse().ok();
+    }
+
+    let (positive, mantissa, exponent) = number.as_parts();
+    let value = format!("{}e{}", mantissa, exponent).parse::<T>().ok()?;
+    if !value.into().is_finite() {
+        return None;
     }
+    Some(if positive { value } else { -value })
 }
@@ -0,0 +1,240 @@
+use crate::DynErr;
+use std::error::Error;
+use std::fmt;
+use thiserror::Error;
+
+/// A setting value which is well-formed but violates the constraints of its
+/// type.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    #[error("{value} is below the {} minimum of {bound}", if *.inclusive { "inclusive" } else { "exclusive" })]
+    BelowMinimum {
+        value: String,
+        bound: String,
+        inclusive: bool,
+    },
+    #[error("{value} is above the {} maximum of {bound}", if *.inclusive { "inclusive" } else { "exclusive" })]
+    AboveMaximum {
+        value: String,
+        bound: String,
+        inclusive: bool,
+    },
+    #[error("range minimum {min} is greater than its maximum {max}")]
+    InvertedRange { min: String, max: String },
+    #[error("value must be non-zero")]
+    Zero,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// The location of a value within a JSON document, displayed as
+/// `thresholds[2].max`, or `$` for the document itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Path(pub Vec<PathSegment>);
+
+impl Path {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_root() {
+            return write!(f, "$");
+        }
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ErrorKind {
+    #[error("missing field")]
+    Missing,
+    #[error("expected {0}")]
+    WrongType(String),
+    #[error(transparent)]
+    OutOfRange(#[from] ValidationError),
+    #[error("invalid JSON at line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[error("{0}")]
+    Invalid(DynErr),
+}
+
+/// A single invalid value and where it was found.
+#[derive(Error, Debug)]
+#[error("{path}: {kind}")]
+pub struct FieldError {
+    pub path: Path,
+    pub kind: ErrorKind,
+}
+
+/// Every error found while deserializing a value. Fields are read
+/// independently, so one bad field does not hide the others.
+#[derive(Debug)]
+pub struct DeserializationError {
+    errors: Vec<FieldError>,
+}
+
+impl DeserializationError {
+    pub fn new(kind: impl Into<ErrorKind>) -> Self {
+        Self {
+            errors: vec![FieldError {
+                path: Path::default(),
+                kind: kind.into(),
+            }],
+        }
+    }
+
+    pub fn missing(field: &str) -> Self {
+        Self::new(ErrorKind::Missing).in_field(field)
+    }
+
+    pub fn wrong_type(expected: impl Into<String>) -> Self {
+        Self::new(ErrorKind::WrongType(expected.into()))
+    }
+
+    pub fn invalid(error: impl Into<DynErr>) -> Self {
+        Self::new(ErrorKind::Invalid(error.into()))
+    }
+
+    /// Locates a `json` parse error in `input`. Errors without a position
+    /// are reported at the end of the input.
+    pub fn parse(input: &str, error: json::Error) -> Self {
+        let (line, column, message) = match error {
+            json::Error::UnexpectedCharacter { ch, line, column } => {
+                (line, column, format!("unexpected character `{}`", ch))
+            }
+            json::Error::UnexpectedEndOfJson => {
+                let (line, column) = end_of(input);
+                (line, column, "unexpected end of input".to_owned())
+            }
+            error => {
+                let (line, column) = end_of(input);
+                (line, column, error.to_string())
+            }
+        };
+        Self::new(ErrorKind::Parse {
+            line,
+            column,
+            message,
+        })
+    }
+
+    /// Prefixes the path of every error with the field `name`.
+    pub fn in_field(self, name: &str) -> Self {
+        self.prefixed(PathSegment::Field(name.to_owned()))
+    }
+
+    /// Prefixes the path of every error with the element `index`.
+    pub fn at_index(self, index: usize) -> Self {
+        self.prefixed(PathSegment::Index(index))
+    }
+
+    fn prefixed(mut self, segment: PathSegment) -> Self {
+        for error in &mut self.errors {
+            error.path.0.insert(0, segment.clone());
+        }
+        self
+    }
+
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<FieldError> {
+        self.errors
+    }
+}
+
+impl fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let [error] = self.errors.as_slice() {
+            return write!(f, "{}", error);
+        }
+        write!(f, "{} invalid values", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n  {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for DeserializationError {}
+
+impl From<ErrorKind> for DeserializationError {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<ValidationError> for DeserializationError {
+    fn from(error: ValidationError) -> Self {
+        Self::new(error)
+    }
+}
+
+/// Collects the errors of values read independently of each other, such as
+/// the fields of an object.
+#[derive(Debug, Default)]
+pub struct ErrorCollector {
+    errors: Vec<FieldError>,
+}
+
+impl ErrorCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the error of `result`, if any, returning its value otherwise.
+    pub fn check<T>(&mut self, result: Result<T, DeserializationError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    pub fn push(&mut self, error: DeserializationError) {
+        self.errors.extend(error.errors);
+    }
+
+    /// Fails with every recorded error, if there were any.
+    pub fn finish(self) -> Result<(), DeserializationError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(DeserializationError {
+                errors: self.errors,
+            })
+        }
+    }
+}
+
+// Line and column are 1-based, like those reported by `json`.
+fn end_of(input: &str) -> (usize, usize) {
+    let line = input.matches('\n').count() + 1;
+    let column = input.rsplit('\n').next().map_or(0, |e| e.chars().count()) + 1;
+    (line, column)
+}
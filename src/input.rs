@@ -1,5 +1,6 @@
-use crate::DeserializationError;
+use crate::port::PortType;
 use crate::schema::Type;
+use crate::{DeserializationError, ErrorCollector};
 use std::any::Any;
 use std::collections::HashMap;
 
@@ -8,24 +9,53 @@ pub trait Input<'a>: Sized {
     fn schema() -> HashMap<String, Type>;
 }
 
+/// Reads the slots `<name>.0`, `<name>.1`, ... of a variadic input, in order.
+/// Slots may be missing, so disconnecting one does not renumber the others.
+pub fn variadic<'a, T: PortType>(
+    map: &'a HashMap<String, &dyn Any>,
+    name: &str,
+) -> Result<Vec<&'a T>, DeserializationError> {
+    let prefix = format!("{}.", name);
+    let mut slots = map
+        .iter()
+        .filter_map(|(key, value)| {
+            let index = key.strip_prefix(&prefix)?.parse::<usize>().ok()?;
+            Some((index, value))
+        })
+        .collect::<Vec<_>>();
+    slots.sort_by_key(|(index, _)| *index);
+
+    let mut errors = ErrorCollector::new();
+    let values = slots
+        .into_iter()
+        .filter_map(|(index, value)| {
+            errors.check(value.downcast_ref::<T>().ok_or_else(|| {
+                DeserializationError::wrong_type(T::name())
+                    .at_index(index)
+                    .in_field(name)
+            }))
+        })
+        .collect();
+    errors.finish().map(|()| values)
+}
+
 pub struct InputSingular<'a, T: 'static> {
     pub val: &'a T,
 }
 
-impl<'a, T: 'static> Input<'a> for InputSingular<'a, T> {
+impl<'a, T: PortType> Input<'a> for InputSingular<'a, T> {
     fn from_any_map(map: &'a HashMap<String, &dyn Any>) -> Result<Self, DeserializationError> {
         Ok(Self {
-            val: map.get("val").ok_or_else(|| DeserializationError::MissingField("val".to_owned()))?.downcast_ref::<T>().ok_or_else(|| DeserializationError::TypeError("val".to_owned()))?,
+            val: map
+                .get("val")
+                .ok_or_else(|| DeserializationError::missing("val"))?
+                .downcast_ref::<T>()
+                .ok_or_else(|| DeserializationError::wrong_type(T::name()).in_field("val"))?,
         })
     }
     fn schema() -> HashMap<String, Type> {
         let mut map = HashMap::new();
-        map.insert(
-            "val".to_owned(),
-            Type {
-                name: ::std::any::type_name::<T>().to_owned(),
-            },
-        );
+        map.insert("val".to_owned(), Type::required::<T>());
         map
     }
 }
@@ -0,0 +1,202 @@
+use crate::schema::{settings_from_json, SettingType};
+use crate::Configurable;
+use json::JsonValue;
+use std::collections::HashMap;
+
+pub const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const INTEGERS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+];
+
+pub fn configurable_schema<C: Configurable>() -> JsonValue {
+    settings_schema(&C::schema())
+}
+
+/// Builds a JSON Schema (draft 2020-12) document describing a settings
+/// object, as returned by `Configurable::schema`.
+pub fn settings_schema(settings: &HashMap<String, SettingType>) -> JsonValue {
+    let mut json = JsonValue::new_object();
+    json["$schema"] = DRAFT.into();
+    for (key, value) in object_schema(settings).entries() {
+        json[key] = value.clone();
+    }
+    json
+}
+
+// Unknown keys are ignored when deserializing, so that configs saved with a
+// since removed setting still load, and are allowed here to match.
+fn object_schema(settings: &HashMap<String, SettingType>) -> JsonValue {
+    let mut names = settings.keys().collect::<Vec<_>>();
+    names.sort();
+
+    let mut properties = JsonValue::new_object();
+    for name in &names {
+        properties[name.as_str()] = setting_schema(&settings[*name]);
+    }
+    let required = names
+        .into_iter()
+        .filter(|e| !settings[*e].optional())
+        .map(|e| e.as_str())
+        .collect::<Vec<_>>();
+
+    let mut json = JsonValue::new_object();
+    json["type"] = "object".into();
+    json["properties"] = properties;
+    json["required"] = required.into();
+    json
+}
+
+/// Builds the JSON Schema for a single setting. Settings of unknown types
+/// produce a schema which accepts any value.
+pub fn setting_schema(setting: &SettingType) -> JsonValue {
+    let mut json = value_schema(setting);
+    if let Some(default) = setting.params.get("default") {
+        json["default"] = default.clone();
+    }
+    json
+}
+
+fn value_schema(setting: &SettingType) -> JsonValue {
+    let name = setting.name.as_str();
+
+    if INTEGERS.contains(&name) || name.starts_with("Constrained") {
+        bounded_schema(number_type(name), &setting.params)
+    } else if name.starts_with("NonZero") {
+        let mut json = bounded_schema("integer", &setting.params);
+        json["not"]["const"] = 0.into();
+        json
+    } else if name.starts_with("Range") {
+        let bound = bounded_schema(number_type(name), &setting.params);
+
+        let mut properties = JsonValue::new_object();
+        properties["min"] = bound.clone();
+        properties["max"] = bound;
+
+        let mut json = JsonValue::new_object();
+        json["type"] = "object".into();
+        json["properties"] = properties;
+        json["required"] = vec!["min", "max"].into();
+        json
+    } else {
+        match name {
+            "f32" | "f64" => bounded_schema("number", &setting.params),
+            "bool" => type_schema("boolean"),
+            "string" => type_schema("string"),
+            "enum" => match setting.params.get("variants") {
+                Some(variants) if variants.is_array() => {
+                    let mut json = type_schema("string");
+                    json["enum"] = variants.clone();
+                    json
+                }
+                _ => JsonValue::new_object(),
+            },
+            "tagged_enum" => tagged_enum_schema(&setting.params),
+            "object" => match setting.params.get("fields") {
+                Some(fields) => object_schema(&settings_from_json(fields).unwrap_or_default()),
+                None => JsonValue::new_object(),
+            },
+            "option" => {
+                let mut json = JsonValue::new_object();
+                json["anyOf"] = vec![
+                    element_schema(setting.params.get("element")),
+                    type_schema("null"),
+                ]
+                .into();
+                json
+            }
+            "list" | "array" => {
+                let mut json = type_schema("array");
+                json["items"] = element_schema(setting.params.get("element"));
+                if let Some(length) = setting.params.get("length") {
+                    json["minItems"] = length.clone();
+                    json["maxItems"] = length.clone();
+                }
+                json
+            }
+            "map" => {
+                let mut json = type_schema("object");
+                json["additionalProperties"] = element_schema(setting.params.get("element"));
+                json
+            }
+            "tuple" => {
+                let elements = match setting.params.get("elements") {
+                    Some(elements) if elements.is_array() => elements
+                        .members()
+                        .map(|e| element_schema(Some(e)))
+                        .collect::<Vec<_>>(),
+                    _ => return JsonValue::new_object(),
+                };
+
+                let mut json = type_schema("array");
+                json["minItems"] = elements.len().into();
+                json["maxItems"] = elements.len().into();
+                json["prefixItems"] = elements.into();
+                json["items"] = false.into();
+                json
+            }
+            _ => JsonValue::new_object(),
+        }
+    }
+}
+
+fn tagged_enum_schema(params: &HashMap<String, JsonValue>) -> JsonValue {
+    let tag = params
+        .get("tag")
+        .and_then(JsonValue::as_str)
+        .unwrap_or("type");
+    let entries = match params.get("variants") {
+        Some(entries) if entries.is_object() => entries,
+        _ => return JsonValue::new_object(),
+    };
+
+    let mut variants = JsonValue::new_array();
+    for (name, fields) in entries.entries() {
+        let mut variant = object_schema(&settings_from_json(fields).unwrap_or_default());
+        variant["properties"][tag]["const"] = name.into();
+        variant["required"].push(tag).unwrap();
+        variants.push(variant).unwrap();
+    }
+
+    let mut json = JsonValue::new_object();
+    json["oneOf"] = variants;
+    json
+}
+
+fn element_schema(element: Option<&JsonValue>) -> JsonValue {
+    element
+        .and_then(|e| SettingType::from_json(e).ok())
+        .map_or_else(JsonValue::new_object, |e| setting_schema(&e))
+}
+
+fn type_schema(ty: &str) -> JsonValue {
+    let mut json = JsonValue::new_object();
+    json["type"] = ty.into();
+    json
+}
+
+// `Constrained` and `Range` settings are named after their element type.
+fn number_type(name: &str) -> &'static str {
+    if name.ends_with("F32") || name.ends_with("F64") {
+        "number"
+    } else {
+        "integer"
+    }
+}
+
+fn bounded_schema(ty: &str, params: &HashMap<String, JsonValue>) -> JsonValue {
+    let (minimum, maximum) = if params.get("inclusive") == Some(&JsonValue::Boolean(false)) {
+        ("exclusiveMinimum", "exclusiveMaximum")
+    } else {
+        ("minimum", "maximum")
+    };
+
+    let mut json = type_schema(ty);
+    if let Some(min) = params.get("min") {
+        json[minimum] = min.clone();
+    }
+    if let Some(max) = params.get("max") {
+        json[maximum] = max.clone();
+    }
+    json
+}
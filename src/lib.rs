@@ -1,20 +1,28 @@
-#![allow(incomplete_features)]
-#![feature(generic_associated_types)]
-#![feature(const_generics)]
-
+pub mod connection;
 pub mod editable;
+pub mod error;
 pub mod input;
+pub mod json_schema;
 pub mod output;
+pub mod pipeline;
+pub mod port;
+pub mod registry;
 pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod types;
+pub mod typescript;
 
+pub extern crate inventory;
 pub extern crate json;
 
 use input::Input;
+use json::JsonValue;
 use output::Output;
 use schema::*;
 use std::{any::Any, collections::HashMap, error::Error};
 use thiserror::Error;
+pub use error::{DeserializationError, ErrorCollector, ValidationError};
 pub use vision_traits_derive::*;
 
 pub type DynErr = Box<dyn Error>;
@@ -22,17 +30,26 @@ pub type DynErrResult<T> = Result<T, DynErr>;
 
 pub trait Configurable: Sized + 'static {
     fn schema() -> HashMap<String, SettingType>;
-    // TODO: Change this garbage
-    fn deserialize(input: &str) -> Result<Self, DeserializationError>;
+    fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError>;
+    fn serialize(&self) -> JsonValue;
+
+    /// Parses `input` as JSON before deserializing it.
+    fn from_str(input: &str) -> Result<Self, DeserializationError> {
+        let json = json::parse(input).map_err(|e| DeserializationError::parse(input, e))?;
+        Self::deserialize(&json)
+    }
 }
 
 impl Configurable for () {
     fn schema() -> HashMap<String, SettingType> {
         HashMap::new()
     }
-    fn deserialize(_: &str) -> Result<Self, DeserializationError> {
+    fn deserialize(_: &JsonValue) -> Result<Self, DeserializationError> {
         Ok(())
     }
+    fn serialize(&self) -> JsonValue {
+        JsonValue::new_object()
+    }
 }
 
 pub trait Node: Sized + 'static {
@@ -46,19 +63,6 @@ pub trait Node: Sized + 'static {
     fn process(&mut self, input: Self::I<'_>) -> DynErrResult<Self::O>;
 }
 
-#[derive(Error, Debug)]
-pub enum DeserializationError {
-    #[error("field named `{0}` had an invalid type")]
-    TypeError(String),
-    #[error("field named: `{0}` was not found")]
-    MissingField(String),
-    #[error("field named: `{0}` had error")]
-    FieldDeserializationError(String, Box<dyn Error>),
-    // TODO: REMOVE THIS GARBAGE
-    #[error("json string is not an object")]
-    NotObject,
-}
-
 #[derive(Error, Debug)]
 pub enum NodeCreationError {
     #[error("input could not be deserialized")]
@@ -79,7 +83,8 @@ pub trait NodeProcessable {
     fn get_schema() -> Function
     where
         Self: Sized;
-    fn make(input: &str) -> Result<Box<dyn NodeProcessable>, NodeCreationError>
+    fn schema(&self) -> Function;
+    fn make(input: &JsonValue) -> Result<Box<dyn NodeProcessable>, NodeCreationError>
     where
         Self: Sized;
     fn process(
@@ -98,8 +103,12 @@ impl<T: Node> NodeProcessable for T {
         }
     }
 
-    fn make(input: &str) -> Result<Box<dyn NodeProcessable>, NodeCreationError> {
-        Ok(Box::new(T::make(T::S::deserialize(&input)?)?))
+    fn schema(&self) -> Function {
+        Self::get_schema()
+    }
+
+    fn make(input: &JsonValue) -> Result<Box<dyn NodeProcessable>, NodeCreationError> {
+        Ok(Box::new(T::make(T::S::deserialize(input)?)?))
     }
 
     fn process<'a>(
@@ -1,3 +1,4 @@
+use crate::port::PortType;
 use crate::schema::Type;
 use std::any::Any;
 use std::collections::HashMap;
@@ -11,7 +12,7 @@ pub struct OutputSingular<T: 'static> {
     pub val: T,
 }
 
-impl<T: 'static> Output for OutputSingular<T> {
+impl<T: PortType> Output for OutputSingular<T> {
     fn to_any_map(self) -> HashMap<String, Box<dyn Any>> {
         let mut map = HashMap::new();
         map.insert(
@@ -22,12 +23,7 @@ impl<T: 'static> Output for OutputSingular<T> {
     }
     fn schema() -> HashMap<String, Type> {
         let mut map = HashMap::new();
-        map.insert(
-            "val".to_owned(),
-            Type {
-                name: ::std::any::type_name::<T>().to_owned(),
-            },
-        );
+        map.insert("val".to_owned(), Type::required::<T>());
         map
     }
 }
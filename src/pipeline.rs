@@ -0,0 +1,288 @@
+use crate::connection::{check_connection, input_port, ConnectionError, ConversionRegistry};
+use crate::schema::{Function, Type};
+use crate::{NodeProcessable, NodeProcessingError};
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Port {
+    pub node: NodeId,
+    pub name: String,
+}
+
+impl Port {
+    pub fn new(node: NodeId, name: impl Into<String>) -> Self {
+        Self {
+            node,
+            name: name.into(),
+        }
+    }
+}
+
+/// A connection from an output port of one node to an input port of another.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub from: Port,
+    pub to: Port,
+}
+
+#[derive(Error, Debug)]
+pub enum PipelineError {
+    #[error("node `{0:?}` does not exist in this pipeline")]
+    UnknownNode(NodeId),
+    #[error("input named `{}` of node `{:?}` is already connected", .0.name, .0.node)]
+    InputAlreadyConnected(Port),
+    #[error("pipeline contains a cycle")]
+    Cycle,
+    #[error("edge from `{}` of node `{:?}` to `{}` of node `{:?}` is invalid: {1}", .0.from.name, .0.from.node, .0.to.name, .0.to.node)]
+    InvalidEdge(Edge, Box<ConnectionError>),
+    #[error("conversion from `{}` of node `{:?}` to `{}` of node `{:?}` failed", .0.from.name, .0.from.node, .0.to.name, .0.to.node)]
+    ConversionFailed(Edge),
+    #[error("required input `{}` of node `{:?}` is not connected", .0.name, .0.node)]
+    Unconnected(Port),
+    #[error("{}", invalid_connections(.0))]
+    InvalidConnections(Vec<PipelineError>),
+    #[error("node `{0:?}` failed to process")]
+    ProcessingError(NodeId, #[source] NodeProcessingError),
+}
+
+fn invalid_connections(errors: &[PipelineError]) -> String {
+    let mut message = format!("pipeline has {} invalid connections", errors.len());
+    for error in errors {
+        message.push_str(&format!("\n  {}", error));
+    }
+    message
+}
+
+pub type NodeOutputs = HashMap<String, Box<dyn Any>>;
+
+/// A graph of nodes wired output-to-input, executed in topological order.
+/// Edges between ports of different types are converted with the pipeline's
+/// `ConversionRegistry`.
+#[derive(Default)]
+pub struct Pipeline {
+    nodes: Vec<Box<dyn NodeProcessable>>,
+    schemas: Vec<Function>,
+    edges: Vec<Edge>,
+    order: Option<Vec<NodeId>>,
+    conversions: ConversionRegistry,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_conversions(conversions: ConversionRegistry) -> Self {
+        Self {
+            conversions,
+            ..Self::default()
+        }
+    }
+
+    pub fn conversions_mut(&mut self) -> &mut ConversionRegistry {
+        self.order = None;
+        &mut self.conversions
+    }
+
+    pub fn add_node(&mut self, node: Box<dyn NodeProcessable>) -> NodeId {
+        self.schemas.push(node.schema());
+        self.nodes.push(node);
+        self.order = None;
+        NodeId(self.nodes.len() - 1)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub fn connect(
+        &mut self,
+        from: NodeId,
+        output: &str,
+        to: NodeId,
+        input: &str,
+    ) -> Result<(), PipelineError> {
+        for &id in &[from, to] {
+            if id.0 >= self.nodes.len() {
+                return Err(PipelineError::UnknownNode(id));
+            }
+        }
+
+        let to = Port::new(to, input);
+        if self.edges.iter().any(|e| e.to == to) {
+            return Err(PipelineError::InputAlreadyConnected(to));
+        }
+
+        self.edges.push(Edge {
+            from: Port::new(from, output),
+            to,
+        });
+        self.order = None;
+        Ok(())
+    }
+
+    pub fn disconnect(&mut self, to: NodeId, input: &str) -> Option<Edge> {
+        let index = self
+            .edges
+            .iter()
+            .position(|e| e.to.node == to && e.to.name == input)?;
+        self.order = None;
+        Some(self.edges.remove(index))
+    }
+
+    /// Checks every edge against the schemas of the nodes it connects, and
+    /// that every required input is connected, reporting all problems found.
+    pub fn validate(&self) -> Result<(), PipelineError> {
+        let mut errors = Vec::new();
+        for edge in &self.edges {
+            let from = &self.schemas[edge.from.node.0];
+            let to = &self.schemas[edge.to.node.0];
+            if let Err(error) =
+                check_connection(from, &edge.from.name, to, &edge.to.name, &self.conversions)
+            {
+                errors.push(PipelineError::InvalidEdge(edge.clone(), Box::new(error)));
+            }
+        }
+
+        for (i, schema) in self.schemas.iter().enumerate() {
+            let mut required = schema
+                .inputs
+                .iter()
+                .filter(|(_, ty)| ty.required)
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            required.sort();
+            for name in required {
+                let to = Port::new(NodeId(i), name.as_str());
+                if !self.edges.iter().any(|e| e.to == to) {
+                    errors.push(PipelineError::Unconnected(to));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(PipelineError::InvalidConnections(errors))
+        }
+    }
+
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, PipelineError> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for edge in &self.edges {
+            in_degree[edge.to.node.0] += 1;
+        }
+
+        let mut queue = (0..self.nodes.len())
+            .filter(|&i| in_degree[i] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(i) = queue.pop_front() {
+            order.push(NodeId(i));
+            for edge in self.edges.iter().filter(|e| e.from.node.0 == i) {
+                let target = edge.to.node.0;
+                in_degree[target] -= 1;
+                if in_degree[target] == 0 {
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Ok(order)
+        } else {
+            Err(PipelineError::Cycle)
+        }
+    }
+
+    /// Runs every node once and returns the outputs of the sink nodes, the
+    /// nodes with no outgoing edges. The pipeline is validated before the
+    /// first frame after any change.
+    pub fn process_frame(&mut self) -> Result<HashMap<NodeId, NodeOutputs>, PipelineError> {
+        if self.order.is_none() {
+            let order = self.topological_order()?;
+            self.validate()?;
+            self.order = Some(order);
+        }
+
+        let Pipeline {
+            nodes,
+            schemas,
+            edges,
+            order,
+            conversions,
+        } = self;
+        let order = order.as_ref().unwrap();
+
+        let mut outputs = (0..nodes.len())
+            .map(|_| None)
+            .collect::<Vec<Option<NodeOutputs>>>();
+
+        for &id in order {
+            let result = {
+                let mut inputs = HashMap::<String, &dyn Any>::new();
+                let mut converted = Vec::new();
+                for edge in edges.iter().filter(|e| e.to.node == id) {
+                    let value = match outputs[edge.from.node.0]
+                        .as_ref()
+                        .and_then(|e| e.get(&edge.from.name))
+                    {
+                        Some(value) => &**value,
+                        None => continue,
+                    };
+                    match conversion(schemas, edge) {
+                        Some((from, to)) => match conversions.convert(&from.id, &to.id, value) {
+                            Some(value) => converted.push((edge.to.name.clone(), value)),
+                            // A failed conversion leaves an optional input unconnected.
+                            None if !to.required => {}
+                            None => return Err(PipelineError::ConversionFailed(edge.clone())),
+                        },
+                        None => {
+                            inputs.insert(edge.to.name.clone(), value);
+                        }
+                    }
+                }
+                for (name, value) in &converted {
+                    inputs.insert(name.clone(), &**value);
+                }
+
+                nodes[id.0].process(&inputs)
+            };
+            outputs[id.0] = Some(result.map_err(|e| PipelineError::ProcessingError(id, e))?);
+        }
+
+        Ok(outputs
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !edges.iter().any(|e| e.from.node.0 == *i))
+            .filter_map(|(i, output)| Some((NodeId(i), output?)))
+            .collect())
+    }
+}
+
+// The output and input types of `edge`, if they differ.
+fn conversion<'a>(schemas: &'a [Function], edge: &Edge) -> Option<(&'a Type, &'a Type)> {
+    let from = schemas[edge.from.node.0].outputs.get(&edge.from.name)?;
+    let to = input_port(&schemas[edge.to.node.0], &edge.to.name)?;
+    if from.id == to.id {
+        None
+    } else {
+        Some((from, to))
+    }
+}
@@ -0,0 +1,62 @@
+/// A type which can be passed between nodes. The id is written into saved
+/// pipelines and port schemas, so unlike `std::any::type_name` it must not
+/// change between releases or toolchains.
+///
+/// Derivable with an explicit id, the name defaulting to the type's identifier:
+///
+/// ```
+/// # use vision_traits::PortType;
+/// #[derive(PortType)]
+/// #[id = "my_crate.contour"]
+/// #[description = "A closed outline of a detected shape"]
+/// struct Contour {
+///     points: Vec<(i32, i32)>,
+/// }
+/// ```
+pub trait PortType: 'static {
+    fn id() -> String;
+
+    /// A human readable name for UIs, defaulting to the id.
+    fn name() -> String {
+        Self::id()
+    }
+
+    fn description() -> Option<String> {
+        None
+    }
+}
+
+macro_rules! port_type {
+    ($ty:ty => $id:expr) => {
+        impl PortType for $ty {
+            fn id() -> String {
+                $id.to_owned()
+            }
+        }
+    };
+}
+
+port_type!(u8 => "u8");
+port_type!(u16 => "u16");
+port_type!(u32 => "u32");
+port_type!(u64 => "u64");
+port_type!(usize => "usize");
+port_type!(i8 => "i8");
+port_type!(i16 => "i16");
+port_type!(i32 => "i32");
+port_type!(i64 => "i64");
+port_type!(isize => "isize");
+port_type!(f32 => "f32");
+port_type!(f64 => "f64");
+port_type!(bool => "bool");
+port_type!(String => "string");
+
+impl<T: PortType> PortType for Vec<T> {
+    fn id() -> String {
+        format!("list<{}>", T::id())
+    }
+
+    fn name() -> String {
+        format!("List<{}>", T::name())
+    }
+}
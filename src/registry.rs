@@ -0,0 +1,115 @@
+use crate::schema::Function;
+use crate::{Node, NodeCreationError, NodeProcessable};
+use json::JsonValue;
+use std::collections::HashMap;
+use thiserror::Error;
+
+type MakeFn = fn(&JsonValue) -> Result<Box<dyn NodeProcessable>, NodeCreationError>;
+
+/// Type-erased constructors for a single `Node` type.
+#[derive(Clone, Copy)]
+pub struct NodeFactory {
+    name: &'static str,
+    schema: fn() -> Function,
+    make: MakeFn,
+}
+
+impl NodeFactory {
+    pub const fn new<T: Node>() -> Self {
+        Self {
+            name: T::NAME,
+            schema: <T as NodeProcessable>::get_schema,
+            make: <T as NodeProcessable>::make,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn schema(&self) -> Function {
+        (self.schema)()
+    }
+
+    pub fn make(
+        &self,
+        settings: &JsonValue,
+    ) -> Result<Box<dyn NodeProcessable>, NodeCreationError> {
+        (self.make)(settings)
+    }
+}
+
+inventory::collect!(NodeFactory);
+
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    #[error("node named `{0}` is already registered")]
+    DuplicateNode(String),
+    #[error("node named `{0}` is not registered")]
+    UnknownNode(String),
+    #[error("node creation failed")]
+    CreationError(#[from] NodeCreationError),
+}
+
+#[derive(Clone, Default)]
+pub struct NodeRegistry {
+    factories: HashMap<&'static str, NodeFactory>,
+}
+
+impl NodeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a registry from every node annotated with `#[register_node]`
+    /// in any crate linked into the current binary.
+    pub fn linked() -> Result<Self, RegistryError> {
+        let mut registry = Self::new();
+        for factory in inventory::iter::<NodeFactory> {
+            registry.register_factory(*factory)?;
+        }
+        Ok(registry)
+    }
+
+    pub fn register<T: Node>(&mut self) -> Result<(), RegistryError> {
+        self.register_factory(NodeFactory::new::<T>())
+    }
+
+    pub fn register_factory(&mut self, factory: NodeFactory) -> Result<(), RegistryError> {
+        if self.factories.contains_key(factory.name) {
+            return Err(RegistryError::DuplicateNode(factory.name.to_owned()));
+        }
+        self.factories.insert(factory.name, factory);
+        Ok(())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NodeFactory> {
+        self.factories.get(name)
+    }
+
+    /// Schemas of every registered node, sorted by name.
+    pub fn schemas(&self) -> Vec<Function> {
+        let mut schemas = self
+            .factories
+            .values()
+            .map(NodeFactory::schema)
+            .collect::<Vec<_>>();
+        schemas.sort_by(|a, b| a.name.cmp(&b.name));
+        schemas
+    }
+
+    pub fn make(
+        &self,
+        name: &str,
+        settings: &JsonValue,
+    ) -> Result<Box<dyn NodeProcessable>, RegistryError> {
+        let factory = self
+            .get(name)
+            .ok_or_else(|| RegistryError::UnknownNode(name.to_owned()))?;
+        Ok(factory.make(settings)?)
+    }
+}
@@ -1,9 +1,20 @@
+use crate::port::PortType;
+use crate::{DeserializationError, ErrorCollector};
+use json::object::Object;
 use json::JsonValue;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Type {
+    /// The stable identifier of the port's type, see `PortType::id`.
+    pub id: String,
     pub name: String,
+    pub description: Option<String>,
+    /// Whether the port must be connected. Only inputs may be optional.
+    pub required: bool,
+    /// Whether the port accepts any number of connections, as the slots
+    /// `<port>.0`, `<port>.1`, ... Only inputs may be variadic.
+    pub variadic: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,3 +30,208 @@ pub struct Function {
     pub inputs: HashMap<String, Type>,
     pub outputs: HashMap<String, Type>,
 }
+
+impl Type {
+    /// A port of type `T` which must be connected.
+    pub fn required<T: PortType>() -> Self {
+        Self {
+            id: T::id(),
+            name: T::name(),
+            description: T::description(),
+            required: true,
+            variadic: false,
+        }
+    }
+
+    /// An input of type `T` which may be left unconnected.
+    pub fn optional<T: PortType>() -> Self {
+        Self {
+            required: false,
+            ..Self::required::<T>()
+        }
+    }
+
+    /// An input taking any number of connections of type `T`, none of which
+    /// are required.
+    pub fn variadic<T: PortType>() -> Self {
+        Self {
+            required: false,
+            variadic: true,
+            ..Self::required::<T>()
+        }
+    }
+
+    /// Serializes to `{"id": <string>, "name": <string>, "required": <bool>,
+    /// "variadic": <bool>}`, with a `description` string if there is one.
+    pub fn to_json(&self) -> JsonValue {
+        let mut json = JsonValue::new_object();
+        json["id"] = self.id.clone().into();
+        json["name"] = self.name.clone().into();
+        if let Some(ref description) = self.description {
+            json["description"] = description.clone().into();
+        }
+        json["required"] = self.required.into();
+        json["variadic"] = self.variadic.into();
+        json
+    }
+
+    /// `required` defaults to `true` and `variadic` to `false` when absent.
+    pub fn from_json(input: &JsonValue) -> Result<Self, DeserializationError> {
+        let object = object(input)?;
+        let mut errors = ErrorCollector::new();
+        let id = errors.check(string(object, "id"));
+        let name = errors.check(string(object, "name"));
+        let description = match object.get("description") {
+            Some(_) => errors.check(string(object, "description")).map(Some),
+            None => Some(None),
+        };
+        let required = errors.check(boolean_or(object, "required", true));
+        let variadic = errors.check(boolean_or(object, "variadic", false));
+        errors.finish()?;
+
+        Ok(Self {
+            id: id.unwrap(),
+            name: name.unwrap(),
+            description: description.unwrap(),
+            required: required.unwrap(),
+            variadic: variadic.unwrap(),
+        })
+    }
+}
+
+impl SettingType {
+    /// Serializes to `{"name": <string>, "params": {<param>: <any>, ...}}`.
+    pub fn to_json(&self) -> JsonValue {
+        let mut params = JsonValue::new_object();
+        for (key, value) in &self.params {
+            params[key.as_str()] = value.clone();
+        }
+
+        let mut json = JsonValue::new_object();
+        json["name"] = self.name.clone().into();
+        json["params"] = params;
+        json
+    }
+
+    /// Whether the setting may be left out of its object, as an `option` or
+    /// a setting with a `default`.
+    pub fn optional(&self) -> bool {
+        self.name == "option" || self.params.contains_key("default")
+    }
+
+    pub fn from_json(input: &JsonValue) -> Result<Self, DeserializationError> {
+        let object = object(input)?;
+        let mut errors = ErrorCollector::new();
+        let name = errors.check(string(object, "name"));
+        let params = errors.check(map(object, "params", |value| Ok(value.clone())));
+        errors.finish()?;
+
+        Ok(Self {
+            name: name.unwrap(),
+            params: params.unwrap(),
+        })
+    }
+}
+
+impl Function {
+    /// Serializes to an object with a `name` string and `settings`, `inputs`
+    /// and `outputs` objects keyed by field name, whose values are serialized
+    /// with `SettingType::to_json` and `Type::to_json` respectively.
+    pub fn to_json(&self) -> JsonValue {
+        let mut json = JsonValue::new_object();
+        json["name"] = self.name.clone().into();
+        json["settings"] = map_to_json(&self.settings, SettingType::to_json);
+        json["inputs"] = map_to_json(&self.inputs, Type::to_json);
+        json["outputs"] = map_to_json(&self.outputs, Type::to_json);
+        json
+    }
+
+    pub fn from_json(input: &JsonValue) -> Result<Self, DeserializationError> {
+        let object = object(input)?;
+        let mut errors = ErrorCollector::new();
+        let name = errors.check(string(object, "name"));
+        let settings = errors.check(map(object, "settings", SettingType::from_json));
+        let inputs = errors.check(map(object, "inputs", Type::from_json));
+        let outputs = errors.check(map(object, "outputs", Type::from_json));
+        errors.finish()?;
+
+        Ok(Self {
+            name: name.unwrap(),
+            settings: settings.unwrap(),
+            inputs: inputs.unwrap(),
+            outputs: outputs.unwrap(),
+        })
+    }
+}
+
+/// Serializes settings nested in the params of composite settings, keyed by
+/// field name.
+pub fn settings_to_json(settings: &HashMap<String, SettingType>) -> JsonValue {
+    map_to_json(settings, SettingType::to_json)
+}
+
+pub fn settings_from_json(
+    input: &JsonValue,
+) -> Result<HashMap<String, SettingType>, DeserializationError> {
+    entries(input, SettingType::from_json)
+}
+
+fn map_to_json<T>(map: &HashMap<String, T>, to_json: fn(&T) -> JsonValue) -> JsonValue {
+    let mut json = JsonValue::new_object();
+    for (key, value) in map {
+        json[key.as_str()] = to_json(value);
+    }
+    json
+}
+
+fn object(input: &JsonValue) -> Result<&Object, DeserializationError> {
+    match input {
+        JsonValue::Object(ref object) => Ok(object),
+        _ => Err(DeserializationError::wrong_type("object")),
+    }
+}
+
+fn field<'a>(object: &'a Object, key: &str) -> Result<&'a JsonValue, DeserializationError> {
+    object
+        .get(key)
+        .ok_or_else(|| DeserializationError::missing(key))
+}
+
+fn string(object: &Object, key: &str) -> Result<String, DeserializationError> {
+    field(object, key)?
+        .as_str()
+        .map(|e| e.to_owned())
+        .ok_or_else(|| DeserializationError::wrong_type("string").in_field(key))
+}
+
+fn boolean_or(object: &Object, key: &str, default: bool) -> Result<bool, DeserializationError> {
+    match object.get(key) {
+        Some(value) => value
+            .as_bool()
+            .ok_or_else(|| DeserializationError::wrong_type("bool").in_field(key)),
+        None => Ok(default),
+    }
+}
+
+fn map<T>(
+    object: &Object,
+    key: &str,
+    from_json: fn(&JsonValue) -> Result<T, DeserializationError>,
+) -> Result<HashMap<String, T>, DeserializationError> {
+    entries(field(object, key)?, from_json).map_err(|e| e.in_field(key))
+}
+
+fn entries<T>(
+    input: &JsonValue,
+    from_json: fn(&JsonValue) -> Result<T, DeserializationError>,
+) -> Result<HashMap<String, T>, DeserializationError> {
+    let mut errors = ErrorCollector::new();
+    let entries = object(input)?
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = errors.check(from_json(value).map_err(|e| e.in_field(name)))?;
+            Some((name.to_owned(), value))
+        })
+        .collect();
+    errors.finish().map(|()| entries)
+}
@@ -0,0 +1,202 @@
+//! `serde` support, bridging through `JsonValue` so settings and schemas can
+//! be read from and written to any self-describing format.
+//!
+//! A `Configurable` field of a serde type can use this module directly:
+//!
+//! ```
+//! use serde_derive::Deserialize;
+//! use vision_traits::Configurable;
+//!
+//! #[derive(Configurable)]
+//! struct BlurSettings {
+//!     radius: u8,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Stage {
+//!     node: String,
+//!     #[serde(with = "vision_traits::serde")]
+//!     settings: BlurSettings,
+//! }
+//!
+//! let stage: Stage = toml::from_str("node = 'blur'\n[settings]\nradius = 3").unwrap();
+//! assert_eq!(stage.settings.radius, 3);
+//! ```
+
+use crate::editable::{
+    float_from_json, float_to_json, integer_from_json, integer_to_json, Editable,
+};
+use crate::schema::{Function, SettingType, Type};
+use crate::Configurable;
+use ::serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{SerializeMap, SerializeSeq, Serializer};
+use ::serde::{Deserialize, Serialize};
+use json::JsonValue;
+use std::fmt;
+
+/// A `JsonValue` which can be serialized and deserialized with serde.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json(pub JsonValue);
+
+impl Serialize for Json {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_json(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Json {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonVisitor).map(Json)
+    }
+}
+
+// Serializes nested values without cloning them into a `Json`.
+struct Borrowed<'a>(&'a JsonValue);
+
+impl Serialize for Borrowed<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_json(self.0, serializer)
+    }
+}
+
+fn serialize_json<S: Serializer>(value: &JsonValue, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        JsonValue::Null => serializer.serialize_unit(),
+        JsonValue::Boolean(value) => serializer.serialize_bool(*value),
+        JsonValue::Short(_) | JsonValue::String(_) => {
+            serializer.serialize_str(value.as_str().unwrap())
+        }
+        JsonValue::Number(_) => {
+            if let Some(value) = integer_from_json::<i64>(value) {
+                serializer.serialize_i64(value)
+            } else if let Some(value) = integer_from_json::<u64>(value) {
+                serializer.serialize_u64(value)
+            } else {
+                serializer.serialize_f64(float_from_json(value).unwrap())
+            }
+        }
+        JsonValue::Array(values) => {
+            let mut seq = serializer.serialize_seq(Some(values.len()))?;
+            for value in values {
+                seq.serialize_element(&Borrowed(value))?;
+            }
+            seq.end()
+        }
+        JsonValue::Object(object) => {
+            let mut map = serializer.serialize_map(Some(object.len()))?;
+            for (key, value) in object.iter() {
+                map.serialize_entry(key, &Borrowed(value))?;
+            }
+            map.end()
+        }
+    }
+}
+
+struct JsonVisitor;
+
+impl<'de> Visitor<'de> for JsonVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value representable as JSON")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<JsonValue, E> {
+        Ok(value.into())
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<JsonValue, E> {
+        Ok(integer_to_json(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<JsonValue, E> {
+        Ok(integer_to_json(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<JsonValue, E> {
+        Ok(float_to_json(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<JsonValue, E> {
+        Ok(value.into())
+    }
+
+    fn visit_unit<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<JsonValue, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut values = Vec::new();
+        while let Some(Json(value)) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(JsonValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut object = JsonValue::new_object();
+        while let Some((key, Json(value))) = map.next_entry::<String, Json>()? {
+            object[key.as_str()] = value;
+        }
+        Ok(object)
+    }
+}
+
+/// Serializes a `Configurable` with serde, for `#[serde(with = "...")]`.
+pub fn serialize<C: Configurable, S: Serializer>(
+    value: &C,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_json(&value.serialize(), serializer)
+}
+
+/// Deserializes a `Configurable` with serde, for `#[serde(with = "...")]`.
+pub fn deserialize<'de, C: Configurable, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<C, D::Error> {
+    let Json(value) = Json::deserialize(deserializer)?;
+    C::deserialize(&value).map_err(de::Error::custom)
+}
+
+pub(crate) fn serialize_editable<E: Editable, S: Serializer>(
+    value: &E,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_json(&value.serialize(), serializer)
+}
+
+pub(crate) fn deserialize_editable<'de, E: Editable, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<E, D::Error> {
+    let Json(value) = Json::deserialize(deserializer)?;
+    E::deserialize(&value).map_err(de::Error::custom)
+}
+
+macro_rules! schema_serde {
+    ($ty:ident) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_json(&self.to_json(), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let Json(value) = Json::deserialize(deserializer)?;
+                $ty::from_json(&value).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+schema_serde!(Type);
+schema_serde!(SettingType);
+schema_serde!(Function);
@@ -0,0 +1,23 @@
+/// Bounds of a floating point `Constrained` or `Range` setting, which cannot
+/// be given as const generics.
+///
+/// ```
+/// use vision_traits::types::bounds::FloatBounds;
+/// use vision_traits::types::constrained::ConstrainedF64;
+///
+/// struct Exposure;
+/// impl FloatBounds<f64> for Exposure {
+///     const MIN: f64 = 0.0;
+///     const MAX: f64 = 100.0;
+///     const STEP: Option<f64> = Some(0.5);
+/// }
+///
+/// type ExposureSetting = ConstrainedF64<Exposure>;
+/// ```
+pub trait FloatBounds<T>: 'static {
+    const MIN: T;
+    const MAX: T;
+    const INCLUSIVE: bool = true;
+    /// Increment used by editors. Values are not snapped to it.
+    const STEP: Option<T> = None;
+}
@@ -1,37 +1,149 @@
-use crate::editable::Editable;
+use crate::editable::{
+    bounded_float, bounded_integer, check_bounds, float_bounds, float_to_json, integer_to_json,
+    Editable,
+};
 use crate::schema::SettingType;
+use crate::types::bounds::FloatBounds;
+use crate::{DeserializationError, ValidationError};
 use json::JsonValue;
 use std::collections::HashMap;
-use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
 
 macro_rules! constrained {
-    ($ty:ident => $method:ident) => {
+    ($ty:ident) => {
         paste::item! {
             #[repr(transparent)]
-            #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
             pub struct [<Constrained $ty:camel>]<const MIN: $ty, const MAX: $ty, const BOUNDS_INCLUSIVE: bool>($ty);
+            impl<const MIN: $ty, const MAX: $ty, const BOUNDS_INCLUSIVE: bool> [<Constrained $ty:camel>]<MIN, MAX, BOUNDS_INCLUSIVE> {
+                pub fn new(value: $ty) -> Result<Self, ValidationError> {
+                    check_bounds(&value, &MIN, &MAX, BOUNDS_INCLUSIVE)?;
+                    Ok([<Constrained $ty:camel>](value))
+                }
+                pub fn get(self) -> $ty {
+                    self.0
+                }
+            }
+            /// The minimum, which must be inclusive.
+            impl<const MIN: $ty, const MAX: $ty, const BOUNDS_INCLUSIVE: bool> Default for [<Constrained $ty:camel>]<MIN, MAX, BOUNDS_INCLUSIVE> {
+                fn default() -> Self {
+                    const { assert!(BOUNDS_INCLUSIVE, "exclusive bounds have no default") };
+                    [<Constrained $ty:camel>](MIN)
+                }
+            }
             impl<const MIN: $ty, const MAX: $ty, const BOUNDS_INCLUSIVE: bool> Editable for [<Constrained $ty:camel>]<MIN, MAX, BOUNDS_INCLUSIVE> {
                 fn schema() -> SettingType {
                     let mut map = HashMap::new();
-                    map.insert("min".to_owned(), $ty::MIN.into());
-                    map.insert("max".to_owned(), $ty::MAX.into());
+                    map.insert("min".to_owned(), integer_to_json(MIN));
+                    map.insert("max".to_owned(), integer_to_json(MAX));
+                    map.insert("inclusive".to_owned(), BOUNDS_INCLUSIVE.into());
 
                     SettingType {
                         name: stringify!([<Constrained $ty:camel>]).to_owned(),
                         params: map,
                     }
                 }
-                fn deserialize(input: &JsonValue) -> Result<Self, Box<dyn Error>> {
-                    Ok(input.$method().filter(|e| &MIN <= e && e <= &MAX).map(|e| [<Constrained $ty:camel>](e)).unwrap())
+                fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                    Ok([<Constrained $ty:camel>](bounded_integer(input, MIN, MAX, BOUNDS_INCLUSIVE)?))
+                }
+                fn serialize(&self) -> JsonValue {
+                    integer_to_json(self.0)
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<const MIN: $ty, const MAX: $ty, const BOUNDS_INCLUSIVE: bool> ::serde::Serialize for [<Constrained $ty:camel>]<MIN, MAX, BOUNDS_INCLUSIVE> {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    crate::serde::serialize_editable(self, serializer)
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<'de, const MIN: $ty, const MAX: $ty, const BOUNDS_INCLUSIVE: bool> ::serde::Deserialize<'de> for [<Constrained $ty:camel>]<MIN, MAX, BOUNDS_INCLUSIVE> {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    crate::serde::deserialize_editable(deserializer)
+                }
+            }
+        }
+    };
+}
+
+constrained!(u8);
+constrained!(u16);
+constrained!(u32);
+constrained!(u64);
+constrained!(usize);
+constrained!(i8);
+constrained!(i16);
+constrained!(i32);
+constrained!(i64);
+constrained!(isize);
+
+macro_rules! constrained_float {
+    ($ty:ident) => {
+        paste::item! {
+            #[repr(transparent)]
+            pub struct [<Constrained $ty:camel>]<B: FloatBounds<$ty>>($ty, PhantomData<fn() -> B>);
+            impl<B: FloatBounds<$ty>> Editable for [<Constrained $ty:camel>]<B> {
+                fn schema() -> SettingType {
+                    SettingType {
+                        name: stringify!([<Constrained $ty:camel>]).to_owned(),
+                        params: float_bounds::<$ty, B>(),
+                    }
+                }
+                fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                    Ok([<Constrained $ty:camel>](bounded_float::<$ty, B>(input)?, PhantomData))
+                }
+                fn serialize(&self) -> JsonValue {
+                    float_to_json(self.0)
+                }
+            }
+            impl<B: FloatBounds<$ty>> [<Constrained $ty:camel>]<B> {
+                pub fn new(value: $ty) -> Result<Self, ValidationError> {
+                    check_bounds(&value, &B::MIN, &B::MAX, B::INCLUSIVE)?;
+                    Ok([<Constrained $ty:camel>](value, PhantomData))
+                }
+                pub fn get(self) -> $ty {
+                    self.0
+                }
+            }
+            /// The minimum, which must be inclusive.
+            impl<B: FloatBounds<$ty>> Default for [<Constrained $ty:camel>]<B> {
+                fn default() -> Self {
+                    const { assert!(B::INCLUSIVE, "exclusive bounds have no default") };
+                    [<Constrained $ty:camel>](B::MIN, PhantomData)
+                }
+            }
+            impl<B: FloatBounds<$ty>> fmt::Debug for [<Constrained $ty:camel>]<B> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.debug_tuple(stringify!([<Constrained $ty:camel>])).field(&self.0).finish()
+                }
+            }
+            impl<B: FloatBounds<$ty>> Clone for [<Constrained $ty:camel>]<B> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+            impl<B: FloatBounds<$ty>> Copy for [<Constrained $ty:camel>]<B> {}
+            impl<B: FloatBounds<$ty>> PartialEq for [<Constrained $ty:camel>]<B> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<B: FloatBounds<$ty>> ::serde::Serialize for [<Constrained $ty:camel>]<B> {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    crate::serde::serialize_editable(self, serializer)
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<'de, B: FloatBounds<$ty>> ::serde::Deserialize<'de> for [<Constrained $ty:camel>]<B> {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    crate::serde::deserialize_editable(deserializer)
                 }
             }
         }
     };
 }
 
-constrained!(u8 => as_u8);
-constrained!(u16 => as_u16);
-constrained!(u32 => as_u32);
-constrained!(i8 => as_i8);
-constrained!(i16 => as_i16);
-constrained!(i32 => as_i32);
+constrained_float!(f32);
+constrained_float!(f64);
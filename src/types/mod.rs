@@ -1,2 +1,3 @@
+pub mod bounds;
 pub mod constrained;
 pub mod range;
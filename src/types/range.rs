@@ -1,50 +1,175 @@
-use crate::editable::Editable;
+use crate::editable::{
+    bounded_float, bounded_integer, check_bounds, check_range, float_bounds, float_to_json,
+    integer_to_json, range_bounds, Editable,
+};
 use crate::schema::SettingType;
+use crate::types::bounds::FloatBounds;
+use crate::{DeserializationError, ValidationError};
 use json::JsonValue;
 use std::collections::HashMap;
-use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
 
 macro_rules! range {
-    ($ty:ident => $method:ident) => {
+    ($ty:ident) => {
         paste::item! {
             #[repr(C)]
-            #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
             pub struct [<Range $ty:camel>]<const MIN: $ty, const MAX: $ty> {
-                pub min: $ty,
-                pub max: $ty,
+                min: $ty,
+                max: $ty,
+            }
+            impl<const MIN: $ty, const MAX: $ty> [<Range $ty:camel>]<MIN, MAX> {
+                pub fn new(min: $ty, max: $ty) -> Result<Self, ValidationError> {
+                    check_bounds(&min, &MIN, &MAX, true)?;
+                    check_bounds(&max, &MIN, &MAX, true)?;
+                    check_range(&min, &max)?;
+                    Ok([<Range $ty:camel>] { min, max })
+                }
+                pub fn min(&self) -> $ty {
+                    self.min
+                }
+                pub fn max(&self) -> $ty {
+                    self.max
+                }
+            }
+            /// The whole range between the bounds.
+            impl<const MIN: $ty, const MAX: $ty> Default for [<Range $ty:camel>]<MIN, MAX> {
+                fn default() -> Self {
+                    const { assert!(MIN <= MAX, "MIN must not be greater than MAX") };
+                    [<Range $ty:camel>] { min: MIN, max: MAX }
+                }
             }
             impl<const MIN: $ty, const MAX: $ty> Editable for [<Range $ty:camel>]<MIN, MAX> {
                 fn schema() -> SettingType {
-                    // Suboptimal, I know
-                    if(MIN > MAX) {
-                        panic!("MIN must be less than MAX");
-                    }
+                    const { assert!(MIN <= MAX, "MIN must not be greater than MAX") };
                     let mut map = HashMap::new();
-                    map.insert("min".to_owned(), MIN.into());
-                    map.insert("max".to_owned(), MAX.into());
+                    map.insert("min".to_owned(), integer_to_json(MIN));
+                    map.insert("max".to_owned(), integer_to_json(MAX));
 
                     SettingType {
                         name: stringify!([<Range $ty:camel>]).to_owned(),
                         params: map,
                     }
                 }
-                fn deserialize(input: &JsonValue) -> Result<Self, Box<dyn Error>> {
-                    // Suboptimal, I know
-                    if(MIN > MAX) {
-                        panic!("MIN must be less than MAX");
-                    }
-                    let min = input["min"].$method().filter(|e| &MIN <= e && e <= &MAX).unwrap();
-                    let max = input["max"].$method().filter(|e| &MIN <= e && e <= &MAX).unwrap();
+                fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                    const { assert!(MIN <= MAX, "MIN must not be greater than MAX") };
+                    let (min, max) = range_bounds(input, |e| bounded_integer(e, MIN, MAX, true))?;
                     Ok([<Range $ty:camel>]{ min, max })
                 }
+                fn serialize(&self) -> JsonValue {
+                    let mut json = JsonValue::new_object();
+                    json["min"] = integer_to_json(self.min);
+                    json["max"] = integer_to_json(self.max);
+                    json
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<const MIN: $ty, const MAX: $ty> ::serde::Serialize for [<Range $ty:camel>]<MIN, MAX> {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    crate::serde::serialize_editable(self, serializer)
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<'de, const MIN: $ty, const MAX: $ty> ::serde::Deserialize<'de> for [<Range $ty:camel>]<MIN, MAX> {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    crate::serde::deserialize_editable(deserializer)
+                }
+            }
+        }
+    };
+}
+
+range!(u8);
+range!(u16);
+range!(u32);
+range!(u64);
+range!(usize);
+range!(i8);
+range!(i16);
+range!(i32);
+range!(i64);
+range!(isize);
+
+macro_rules! range_float {
+    ($ty:ident) => {
+        paste::item! {
+            #[repr(C)]
+            pub struct [<Range $ty:camel>]<B: FloatBounds<$ty>> {
+                min: $ty,
+                max: $ty,
+                bounds: PhantomData<fn() -> B>,
+            }
+            impl<B: FloatBounds<$ty>> [<Range $ty:camel>]<B> {
+                pub fn new(min: $ty, max: $ty) -> Result<Self, ValidationError> {
+                    check_bounds(&min, &B::MIN, &B::MAX, B::INCLUSIVE)?;
+                    check_bounds(&max, &B::MIN, &B::MAX, B::INCLUSIVE)?;
+                    check_range(&min, &max)?;
+                    Ok([<Range $ty:camel>] { min, max, bounds: PhantomData })
+                }
+                pub fn min(&self) -> $ty {
+                    self.min
+                }
+                pub fn max(&self) -> $ty {
+                    self.max
+                }
+            }
+            /// The whole range between the bounds, which must be inclusive.
+            impl<B: FloatBounds<$ty>> Default for [<Range $ty:camel>]<B> {
+                fn default() -> Self {
+                    const { assert!(B::INCLUSIVE, "exclusive bounds have no default") };
+                    [<Range $ty:camel>] { min: B::MIN, max: B::MAX, bounds: PhantomData }
+                }
+            }
+            impl<B: FloatBounds<$ty>> Editable for [<Range $ty:camel>]<B> {
+                fn schema() -> SettingType {
+                    SettingType {
+                        name: stringify!([<Range $ty:camel>]).to_owned(),
+                        params: float_bounds::<$ty, B>(),
+                    }
+                }
+                fn deserialize(input: &JsonValue) -> Result<Self, DeserializationError> {
+                    let (min, max) = range_bounds(input, bounded_float::<$ty, B>)?;
+                    Ok([<Range $ty:camel>] { min, max, bounds: PhantomData })
+                }
+                fn serialize(&self) -> JsonValue {
+                    let mut json = JsonValue::new_object();
+                    json["min"] = float_to_json(self.min);
+                    json["max"] = float_to_json(self.max);
+                    json
+                }
+            }
+            impl<B: FloatBounds<$ty>> fmt::Debug for [<Range $ty:camel>]<B> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.debug_struct(stringify!([<Range $ty:camel>])).field("min", &self.min).field("max", &self.max).finish()
+                }
+            }
+            impl<B: FloatBounds<$ty>> Clone for [<Range $ty:camel>]<B> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+            impl<B: FloatBounds<$ty>> Copy for [<Range $ty:camel>]<B> {}
+            impl<B: FloatBounds<$ty>> PartialEq for [<Range $ty:camel>]<B> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.min == other.min && self.max == other.max
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<B: FloatBounds<$ty>> ::serde::Serialize for [<Range $ty:camel>]<B> {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    crate::serde::serialize_editable(self, serializer)
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl<'de, B: FloatBounds<$ty>> ::serde::Deserialize<'de> for [<Range $ty:camel>]<B> {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    crate::serde::deserialize_editable(deserializer)
+                }
             }
         }
     };
 }
 
-range!(u8 => as_u8);
-range!(u16 => as_u16);
-range!(u32 => as_u32);
-range!(i8 => as_i8);
-range!(i16 => as_i16);
-range!(i32 => as_i32);
+range_float!(f32);
+range_float!(f64);
@@ -0,0 +1,275 @@
+use crate::registry::{NodeRegistry, RegistryError};
+use crate::schema::{settings_from_json, Function, SettingType, Type};
+use json::JsonValue;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const NUMBERS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize", "f32", "f64",
+];
+
+// Longer fixed-size arrays are typed as `Array<T>` rather than a tuple.
+const MAX_TUPLE: usize = 16;
+
+/// Generates a `.d.ts` module with `Settings`, `Inputs` and `Outputs`
+/// interfaces for every function.
+pub fn declarations(functions: &[Function]) -> String {
+    let mut functions = functions.iter().collect::<Vec<_>>();
+    functions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut out = String::new();
+    for function in functions {
+        let name = interface_name(&function.name);
+        let settings = setting_properties(&function.settings);
+        let inputs = port_properties(&function.inputs);
+        let outputs = port_properties(&function.outputs);
+
+        write_interface(&mut out, &function.name, &name, "Settings", settings);
+        write_interface(&mut out, &function.name, &name, "Inputs", inputs);
+        write_interface(&mut out, &function.name, &name, "Outputs", outputs);
+    }
+    out
+}
+
+/// Generates declarations for every node annotated with `#[register_node]`
+/// in any crate linked into the current binary.
+pub fn linked_declarations() -> Result<String, RegistryError> {
+    Ok(declarations(&NodeRegistry::linked()?.schemas()))
+}
+
+/// Maps a setting to the TypeScript type of its serialized form.
+pub fn setting_type(setting: &SettingType) -> String {
+    let name = setting.name.as_str();
+
+    if NUMBERS.contains(&name) || name.starts_with("Constrained") || name.starts_with("NonZero") {
+        "number".to_owned()
+    } else if name.starts_with("Range") {
+        "{ min: number; max: number }".to_owned()
+    } else {
+        match name {
+            "bool" => "boolean".to_owned(),
+            "string" => "string".to_owned(),
+            "enum" => match setting.params.get("variants") {
+                Some(variants) if variants.is_array() && !variants.is_empty() => variants
+                    .members()
+                    .map(JsonValue::dump)
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+                _ => "unknown".to_owned(),
+            },
+            "tagged_enum" => match setting.params.get("variants") {
+                Some(variants) if variants.is_object() && !variants.is_empty() => {
+                    let tag = setting
+                        .params
+                        .get("tag")
+                        .and_then(JsonValue::as_str)
+                        .unwrap_or("type");
+                    variants
+                        .entries()
+                        .map(|(name, fields)| {
+                            let fields = settings_from_json(fields).unwrap_or_default();
+                            let tag =
+                                format!("{}: {}", property_name(tag), JsonValue::from(name).dump());
+                            object_type(Some(tag), &fields)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                }
+                _ => "unknown".to_owned(),
+            },
+            "object" => match setting.params.get("fields") {
+                Some(fields) => object_type(None, &settings_from_json(fields).unwrap_or_default()),
+                None => "unknown".to_owned(),
+            },
+            "option" => format!("{} | null", element_type(setting.params.get("element"))),
+            "list" => format!("Array<{}>", element_type(setting.params.get("element"))),
+            "array" => {
+                let element = element_type(setting.params.get("element"));
+                match setting.params.get("length").and_then(JsonValue::as_usize) {
+                    Some(length) if length <= MAX_TUPLE => {
+                        format!("[{}]", vec![element; length].join(", "))
+                    }
+                    _ => format!("Array<{}>", element),
+                }
+            }
+            "map" => format!(
+                "Record<string, {}>",
+                element_type(setting.params.get("element"))
+            ),
+            "tuple" => match setting.params.get("elements") {
+                Some(elements) if elements.is_array() => format!(
+                    "[{}]",
+                    elements
+                        .members()
+                        .map(|e| element_type(Some(e)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                _ => "unknown".to_owned(),
+            },
+            _ => "unknown".to_owned(),
+        }
+    }
+}
+
+fn element_type(element: Option<&JsonValue>) -> String {
+    element
+        .and_then(|e| SettingType::from_json(e).ok())
+        .map_or_else(|| "unknown".to_owned(), |e| setting_type(&e))
+}
+
+fn object_type(first: Option<String>, settings: &HashMap<String, SettingType>) -> String {
+    let properties = first
+        .into_iter()
+        .chain(
+            setting_properties(settings)
+                .into_iter()
+                .map(|(key, ty, _)| format!("{}: {}", key, ty)),
+        )
+        .collect::<Vec<_>>();
+
+    if properties.is_empty() {
+        "{}".to_owned()
+    } else {
+        format!("{{ {} }}", properties.join("; "))
+    }
+}
+
+/// Maps a port to a TypeScript type, falling back to `unknown` for types
+/// with no JavaScript representation.
+pub fn port_type(ty: &Type) -> String {
+    id_type(&ty.id)
+}
+
+fn id_type(id: &str) -> String {
+    match id {
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" | "f32"
+        | "f64" => "number".to_owned(),
+        "bool" => "boolean".to_owned(),
+        "string" => "string".to_owned(),
+        _ => match id.strip_prefix("list<").and_then(|e| e.strip_suffix('>')) {
+            Some(element) => format!("Array<{}>", id_type(element)),
+            None => "unknown".to_owned(),
+        },
+    }
+}
+
+fn setting_docs(setting: &SettingType) -> Vec<String> {
+    let (minimum, maximum) = if setting.params.get("inclusive") == Some(&JsonValue::Boolean(false))
+    {
+        ("@exclusiveMinimum", "@exclusiveMaximum")
+    } else {
+        ("@minimum", "@maximum")
+    };
+
+    let mut docs = Vec::new();
+    if let Some(min) = setting.params.get("min") {
+        docs.push(format!("{} {}", minimum, min.dump()));
+    }
+    if let Some(max) = setting.params.get("max") {
+        docs.push(format!("{} {}", maximum, max.dump()));
+    }
+    if let Some(step) = setting.params.get("step") {
+        docs.push(format!("@step {}", step.dump()));
+    }
+    if let Some(default) = setting.params.get("default") {
+        docs.push(format!("@default {}", default.dump()));
+    }
+    docs
+}
+
+fn setting_properties(
+    settings: &HashMap<String, SettingType>,
+) -> Vec<(String, String, Vec<String>)> {
+    sorted(settings)
+        .into_iter()
+        .map(|(key, setting)| {
+            let key = if setting.optional() {
+                format!("{}?", property_name(key))
+            } else {
+                property_name(key)
+            };
+            (key, setting_type(setting), setting_docs(setting))
+        })
+        .collect()
+}
+
+fn port_properties(ports: &HashMap<String, Type>) -> Vec<(String, String, Vec<String>)> {
+    sorted(ports)
+        .into_iter()
+        .map(|(key, ty)| {
+            let key = if ty.required {
+                property_name(key)
+            } else {
+                format!("{}?", property_name(key))
+            };
+            let port = if ty.variadic {
+                format!("Array<{}>", port_type(ty))
+            } else {
+                port_type(ty)
+            };
+            let mut docs = vec![format!("{} (`{}`)", ty.name, ty.id)];
+            docs.extend(ty.description.clone());
+            (key, port, docs)
+        })
+        .collect()
+}
+
+fn sorted<T>(map: &HashMap<String, T>) -> Vec<(&String, &T)> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+fn write_interface(
+    out: &mut String,
+    function: &str,
+    name: &str,
+    suffix: &str,
+    properties: Vec<(String, String, Vec<String>)>,
+) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    writeln!(out, "/** {} of the `{}` node. */", suffix, function).unwrap();
+    writeln!(out, "export interface {}{} {{", name, suffix).unwrap();
+    for (key, ty, docs) in properties {
+        match docs.len() {
+            0 => {}
+            1 => writeln!(out, "    /** {} */", docs[0]).unwrap(),
+            _ => {
+                writeln!(out, "    /**").unwrap();
+                for doc in docs {
+                    writeln!(out, "     * {}", doc).unwrap();
+                }
+                writeln!(out, "     */").unwrap();
+            }
+        }
+        writeln!(out, "    {}: {};", key, ty).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+fn interface_name(function: &str) -> String {
+    let mut name = function
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect::<String>();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+fn property_name(key: &str) -> String {
+    let valid = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if valid {
+        key.to_owned()
+    } else {
+        JsonValue::from(key).dump()
+    }
+}
@@ -0,0 +1,7 @@
+use vision_traits::json::JsonValue;
+use vision_traits::{Node, NodeProcessable};
+
+/// Makes a node with default settings.
+pub fn node<T: Node>() -> Box<dyn NodeProcessable> {
+    <T as NodeProcessable>::make(&JsonValue::new_object()).unwrap()
+}
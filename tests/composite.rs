@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use vision_traits::editable::Editable;
+use vision_traits::json::{self, JsonValue};
+use vision_traits::json_schema::{configurable_schema, setting_schema};
+use vision_traits::schema::SettingType;
+use vision_traits::typescript::setting_type;
+use vision_traits::Configurable;
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Settings {
+    label: Option<String>,
+    sizes: Vec<u32>,
+    kernel: [u8; 3],
+    weights: HashMap<String, f64>,
+    point: (i32, bool),
+}
+
+#[test]
+fn missing_option_is_none() {
+    let settings = Settings::from_str(
+        r#"{"sizes": [], "kernel": [1, 2, 3], "weights": {}, "point": [-1, true]}"#,
+    )
+    .unwrap();
+    assert_eq!(settings.label, None);
+    assert_eq!(settings.point, (-1, true));
+
+    let settings = Settings::from_str(
+        r#"{"label": null, "sizes": [], "kernel": [1, 2, 3], "weights": {}, "point": [-1, true]}"#,
+    )
+    .unwrap();
+    assert_eq!(settings.label, None);
+
+    let error = Settings::from_str(r#"{"label": 3}"#).unwrap_err();
+    let mut paths = error
+        .errors()
+        .iter()
+        .map(|e| e.path.to_string())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, ["kernel", "label", "point", "sizes", "weights"]);
+}
+
+#[test]
+fn composite_schemas() {
+    let schema = Settings::schema();
+    assert_eq!(schema["label"].name, "option");
+    assert_eq!(schema["label"].params["element"]["name"], "string");
+    assert_eq!(schema["sizes"].name, "list");
+    assert_eq!(schema["kernel"].name, "array");
+    assert_eq!(schema["kernel"].params["length"], 3);
+    assert_eq!(schema["weights"].name, "map");
+    assert_eq!(schema["point"].name, "tuple");
+    assert_eq!(
+        schema["point"].params["elements"][1],
+        bool::schema().to_json()
+    );
+    assert!(schema["label"].optional());
+    assert!(!schema["sizes"].optional());
+}
+
+#[test]
+fn composite_json_schema() {
+    let schema = configurable_schema::<Settings>();
+    assert_eq!(
+        schema["required"],
+        json::array!["kernel", "point", "sizes", "weights"]
+    );
+
+    let properties = &schema["properties"];
+    assert_eq!(
+        properties["label"].dump(),
+        r#"{"anyOf":[{"type":"string"},{"type":"null"}]}"#
+    );
+    assert_eq!(
+        properties["sizes"].dump(),
+        r#"{"type":"array","items":{"type":"integer","minimum":0,"maximum":4294967295}}"#
+    );
+    assert_eq!(properties["kernel"]["minItems"], 3);
+    assert_eq!(properties["kernel"]["maxItems"], 3);
+    assert_eq!(
+        properties["weights"].dump(),
+        r#"{"type":"object","additionalProperties":{"type":"number"}}"#
+    );
+    assert_eq!(
+        properties["point"].dump(),
+        r#"{"type":"array","minItems":2,"maxItems":2,"prefixItems":[{"type":"integer","minimum":-2147483648,"maximum":2147483647},{"type":"boolean"}],"items":false}"#
+    );
+}
+
+#[test]
+fn composite_declarations() {
+    let schema = Settings::schema();
+    assert_eq!(setting_type(&schema["label"]), "string | null");
+    assert_eq!(setting_type(&schema["sizes"]), "Array<number>");
+    assert_eq!(setting_type(&schema["kernel"]), "[number, number, number]");
+    assert_eq!(setting_type(&<[u8; 17]>::schema()), "Array<number>");
+    assert_eq!(setting_type(&schema["weights"]), "Record<string, number>");
+    assert_eq!(setting_type(&schema["point"]), "[number, boolean]");
+
+    let function = vision_traits::schema::Function {
+        name: "composite".to_owned(),
+        settings: schema,
+        inputs: HashMap::new(),
+        outputs: HashMap::new(),
+    };
+    let declarations = vision_traits::typescript::declarations(&[function]);
+    assert!(
+        declarations.contains("    label?: string | null;"),
+        "{}",
+        declarations
+    );
+    assert!(
+        declarations.contains("    sizes: Array<number>;"),
+        "{}",
+        declarations
+    );
+}
+
+#[test]
+fn malformed_composites() {
+    for name in &["option", "list", "array", "map"] {
+        let setting = SettingType {
+            name: (*name).to_owned(),
+            params: HashMap::new(),
+        };
+        assert!(setting_type(&setting).contains("unknown"), "{}", name);
+        setting_schema(&setting);
+    }
+
+    let mut params = HashMap::new();
+    params.insert("element".to_owned(), u8::schema().to_json());
+    let setting = SettingType {
+        name: "array".to_owned(),
+        params,
+    };
+    assert_eq!(setting_type(&setting), "Array<number>");
+
+    let setting = SettingType {
+        name: "tuple".to_owned(),
+        params: HashMap::new(),
+    };
+    assert_eq!(setting_type(&setting), "unknown");
+    assert_eq!(setting_schema(&setting), JsonValue::new_object());
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::node;
+use vision_traits::connection::{check_connection, ConnectionError, ConversionRegistry};
+use vision_traits::pipeline::{Edge, Pipeline, PipelineError, Port};
+use vision_traits::{vision_node, NodeProcessable, PortType};
+
+#[derive(PortType, Clone, Debug, PartialEq)]
+#[id = "test.contour"]
+struct Contour(u32);
+
+#[vision_node]
+fn find_contours() -> Vec<Contour> {
+    vec![Contour(3), Contour(5)]
+}
+
+#[vision_node]
+fn no_contours() -> Vec<Contour> {
+    Vec::new()
+}
+
+#[vision_node]
+fn area(contour: &Contour) -> u32 {
+    contour.0 * 2
+}
+
+#[vision_node]
+fn largest_area(contour: Option<&Contour>) -> u32 {
+    contour.map_or(0, |contour| contour.0 * 2)
+}
+
+#[vision_node]
+fn total(values: Vec<&u32>) -> u32 {
+    values.into_iter().sum()
+}
+
+fn first_contour() -> ConversionRegistry {
+    let mut conversions = ConversionRegistry::new();
+    conversions.register(|contours: &Vec<Contour>| contours.first().cloned());
+    conversions
+}
+
+#[test]
+fn check_schemas() {
+    let find = FindContours::get_schema();
+    let area = Area::get_schema();
+    let total = Total::get_schema();
+    let none = ConversionRegistry::new();
+
+    let error = check_connection(&find, "val", &area, "contour", &none).unwrap_err();
+    assert_eq!(
+        error,
+        ConnectionError::Incompatible {
+            from: "List<Contour>".to_owned(),
+            from_id: "list<test.contour>".to_owned(),
+            to: "Contour".to_owned(),
+            to_id: "test.contour".to_owned(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "List<Contour> (`list<test.contour>`) cannot be converted to Contour (`test.contour`)"
+    );
+    assert_eq!(
+        check_connection(&find, "val", &area, "contour", &first_contour()),
+        Ok(())
+    );
+    assert_eq!(
+        check_connection(&find, "contours", &area, "contour", &none),
+        Err(ConnectionError::UnknownOutput("contours".to_owned()))
+    );
+    assert_eq!(
+        check_connection(&area, "val", &total, "values.3", &none),
+        Ok(())
+    );
+    assert_eq!(
+        check_connection(&area, "val", &total, "values", &none),
+        Err(ConnectionError::UnknownInput("values".to_owned()))
+    );
+}
+
+#[test]
+fn every_problem_is_reported() {
+    let mut pipeline = Pipeline::new();
+    let find = pipeline.add_node(node::<FindContours>());
+    let area = pipeline.add_node(node::<Area>());
+    let total = pipeline.add_node(node::<Total>());
+    pipeline.connect(find, "val", total, "values.0").unwrap();
+
+    let errors = match pipeline.process_frame() {
+        Err(PipelineError::InvalidConnections(errors)) => errors,
+        other => panic!("expected invalid connections, got {:?}", other.err()),
+    };
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0],
+        PipelineError::InvalidEdge(_, ref error)
+            if matches!(**error, ConnectionError::Incompatible { .. })
+    ));
+    assert!(matches!(errors[1], PipelineError::Unconnected(ref port) if port.node == area));
+}
+
+#[test]
+fn conversions_are_applied() {
+    let mut pipeline = Pipeline::with_conversions(first_contour());
+    let find = pipeline.add_node(node::<FindContours>());
+    let area = pipeline.add_node(node::<Area>());
+    let total = pipeline.add_node(node::<Total>());
+    pipeline.connect(find, "val", area, "contour").unwrap();
+    pipeline.connect(area, "val", total, "values.0").unwrap();
+    pipeline.connect(area, "val", total, "values.1").unwrap();
+
+    let outputs = pipeline.process_frame().unwrap();
+    assert_eq!(outputs[&total]["val"].downcast_ref::<u32>(), Some(&12));
+}
+
+#[test]
+fn failed_conversion_of_required_input() {
+    let mut pipeline = Pipeline::with_conversions(first_contour());
+    let find = pipeline.add_node(node::<NoContours>());
+    let area = pipeline.add_node(node::<Area>());
+    pipeline.connect(find, "val", area, "contour").unwrap();
+
+    let edge = Edge {
+        from: Port::new(find, "val"),
+        to: Port::new(area, "contour"),
+    };
+    assert!(matches!(
+        pipeline.process_frame(),
+        Err(PipelineError::ConversionFailed(ref failed)) if *failed == edge
+    ));
+}
+
+#[test]
+fn failed_conversion_leaves_optional_input_unconnected() {
+    let mut pipeline = Pipeline::with_conversions(first_contour());
+    let find = pipeline.add_node(node::<NoContours>());
+    let area = pipeline.add_node(node::<LargestArea>());
+    pipeline.connect(find, "val", area, "contour").unwrap();
+
+    let outputs = pipeline.process_frame().unwrap();
+    assert_eq!(outputs[&area]["val"].downcast_ref::<u32>(), Some(&0));
+}
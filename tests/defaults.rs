@@ -0,0 +1,146 @@
+use vision_traits::json::{self, JsonValue};
+use vision_traits::json_schema::configurable_schema;
+use vision_traits::schema::Function;
+use vision_traits::types::constrained::ConstrainedU8;
+use vision_traits::types::range::RangeU8;
+use vision_traits::typescript::declarations;
+use vision_traits::Configurable;
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Settings {
+    exposure: u8,
+    #[default]
+    label: String,
+    #[default(vec![3, 5])]
+    kernels: Vec<u32>,
+    #[default = 1.5]
+    gain: f64,
+    #[name = "auto exposure"]
+    #[default = true]
+    auto_exposure: bool,
+    #[default = "front"]
+    camera: String,
+    #[default = 2]
+    sigma: f64,
+    #[default = 5000000000]
+    frames: u64,
+}
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Bounded {
+    #[default]
+    threshold: ConstrainedU8<10, 20, true>,
+    #[default]
+    hue: RangeU8<10, 20>,
+}
+
+#[derive(Configurable, Debug)]
+struct Invalid {
+    #[default = 5]
+    threshold: ConstrainedU8<10, 20, true>,
+}
+
+#[test]
+fn missing_fields_take_defaults() {
+    let settings = Settings::from_str(r#"{"exposure": 12}"#).unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            exposure: 12,
+            label: String::new(),
+            kernels: vec![3, 5],
+            gain: 1.5,
+            auto_exposure: true,
+            camera: "front".to_owned(),
+            sigma: 2.0,
+            frames: 5_000_000_000,
+        }
+    );
+
+    let settings = Settings::from_str(
+        r#"{"exposure": 12, "label": "a", "kernels": [], "gain": 0, "auto exposure": false}"#,
+    )
+    .unwrap();
+    assert_eq!(settings.label, "a");
+    assert!(settings.kernels.is_empty());
+    assert_eq!(settings.gain, 0.0);
+    assert!(!settings.auto_exposure);
+}
+
+#[test]
+fn defaults_respect_bounds() {
+    let settings = Bounded::from_str("{}").unwrap();
+    assert_eq!(settings.threshold.get(), 10);
+    assert_eq!((settings.hue.min(), settings.hue.max()), (10, 20));
+    assert_eq!(
+        Bounded::deserialize(&settings.serialize()).unwrap(),
+        settings
+    );
+
+    let schema = Bounded::schema();
+    assert_eq!(schema["threshold"].params["default"], 10);
+    assert_eq!(
+        schema["hue"].params["default"].dump(),
+        r#"{"min":10,"max":20}"#
+    );
+}
+
+#[test]
+fn invalid_defaults_are_reported() {
+    let error = Invalid::from_str("{}").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "threshold: 5 is below the inclusive minimum of 10"
+    );
+}
+
+#[test]
+fn defaults_do_not_hide_errors() {
+    let error = Settings::from_str(r#"{"gain": "high"}"#).unwrap_err();
+    let mut paths = error
+        .errors()
+        .iter()
+        .map(|e| e.path.to_string())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, ["exposure", "gain"]);
+}
+
+#[test]
+fn defaults_are_published() {
+    let schema = Settings::schema();
+    assert!(!schema["exposure"].params.contains_key("default"));
+    assert_eq!(schema["label"].params["default"], "");
+    assert_eq!(schema["kernels"].params["default"], json::array![3, 5]);
+    assert_eq!(schema["gain"].params["default"], 1.5);
+    assert_eq!(schema["auto exposure"].params["default"], true);
+    assert_eq!(schema["camera"].params["default"], "front");
+    assert_eq!(schema["sigma"].params["default"], 2);
+    assert_eq!(schema["frames"].params["default"].dump(), "5000000000");
+}
+
+#[test]
+fn defaults_are_optional() {
+    let schema = configurable_schema::<Settings>();
+    assert_eq!(schema["required"], json::array!["exposure"]);
+    assert_eq!(schema["properties"]["gain"]["default"], 1.5);
+    assert_eq!(schema["properties"]["exposure"]["default"], JsonValue::Null);
+
+    let function = Function {
+        name: "camera".to_owned(),
+        settings: Settings::schema(),
+        inputs: Default::default(),
+        outputs: Default::default(),
+    };
+    let declarations = declarations(&[function]);
+    for property in &[
+        "    exposure: number;",
+        "    label?: string;",
+        "    kernels?: Array<number>;",
+        "    gain?: number;",
+        "    \"auto exposure\"?: boolean;",
+        "    /** @default 1.5 */",
+    ] {
+        assert!(declarations.contains(property), "{}", declarations);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use vision_traits::editable::Editable;
+use vision_traits::error::ErrorKind;
+use vision_traits::json::{self, JsonValue};
+use vision_traits::json_schema::setting_schema;
+use vision_traits::schema::SettingType;
+use vision_traits::typescript::setting_type;
+use vision_traits::{DeserializationError, Editable};
+
+#[derive(Editable, Debug, PartialEq)]
+enum Interpolation {
+    Nearest,
+    #[name = "bilinear"]
+    Linear,
+    Cubic,
+}
+
+#[derive(Editable, Debug, PartialEq)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    Square(u32),
+    #[name = "point"]
+    Point,
+}
+
+fn parse(input: &str) -> JsonValue {
+    json::parse(input).unwrap()
+}
+
+// The path and message of the only error in `error`.
+fn single(error: DeserializationError) -> (String, String) {
+    let errors = error.into_errors();
+    assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+    (errors[0].path.to_string(), errors[0].kind.to_string())
+}
+
+#[test]
+fn fieldless_schema() {
+    let schema = Interpolation::schema();
+    assert_eq!(schema.name, "enum");
+    assert_eq!(
+        schema.params["variants"],
+        json::array!["Nearest", "bilinear", "Cubic"]
+    );
+    assert_eq!(setting_type(&schema), r#""Nearest" | "bilinear" | "Cubic""#);
+    assert_eq!(
+        setting_schema(&schema).dump(),
+        r#"{"type":"string","enum":["Nearest","bilinear","Cubic"]}"#
+    );
+}
+
+#[test]
+fn fieldless_round_trip() {
+    for variant in [
+        Interpolation::Nearest,
+        Interpolation::Linear,
+        Interpolation::Cubic,
+    ] {
+        assert_eq!(
+            Interpolation::deserialize(&variant.serialize()).unwrap(),
+            variant
+        );
+    }
+    assert_eq!(Interpolation::Linear.serialize(), "bilinear");
+}
+
+#[test]
+fn fieldless_errors() {
+    let (path, message) = single(Interpolation::deserialize(&"Linear".into()).unwrap_err());
+    assert_eq!(path, "$");
+    assert_eq!(
+        message,
+        r#"unknown variant `Linear`, expected one of ["Nearest", "bilinear", "Cubic"]"#
+    );
+
+    let error = Interpolation::deserialize(&3.into()).unwrap_err();
+    assert!(matches!(
+        error.errors()[0].kind,
+        ErrorKind::WrongType(ref expected) if expected == "variant name"
+    ));
+}
+
+#[test]
+fn tagged_schema() {
+    let schema = Shape::schema();
+    assert_eq!(schema.name, "tagged_enum");
+    assert_eq!(schema.params["tag"], "type");
+    assert_eq!(schema.params["variants"]["circle"], JsonValue::Null);
+    assert_eq!(schema.params["variants"]["Circle"]["radius"]["name"], "f64");
+    assert_eq!(schema.params["variants"]["Square"]["value"]["name"], "u32");
+    assert_eq!(schema.params["variants"]["point"], JsonValue::new_object());
+
+    assert_eq!(
+        setting_type(&schema),
+        r#"{ type: "Circle"; radius: number } | { type: "Square"; value: number } | { type: "point" }"#
+    );
+
+    let json_schema = setting_schema(&schema);
+    let variants = &json_schema["oneOf"];
+    assert_eq!(variants.len(), 3);
+    assert_eq!(variants[0]["properties"]["type"]["const"], "Circle");
+    assert_eq!(variants[0]["required"], json::array!["radius", "type"]);
+    assert_eq!(variants[1]["properties"]["value"]["type"], "integer");
+    assert_eq!(variants[2]["required"], json::array!["type"]);
+}
+
+#[test]
+fn tagged_round_trip() {
+    for variant in [
+        Shape::Circle { radius: 2.5 },
+        Shape::Square(4),
+        Shape::Point,
+    ] {
+        assert_eq!(Shape::deserialize(&variant.serialize()).unwrap(), variant);
+    }
+    assert_eq!(
+        Shape::Circle { radius: 2.5 }.serialize().dump(),
+        r#"{"type":"Circle","radius":2.5}"#
+    );
+    assert_eq!(
+        Shape::Square(4).serialize().dump(),
+        r#"{"type":"Square","value":4}"#
+    );
+    assert_eq!(Shape::Point.serialize().dump(), r#"{"type":"point"}"#);
+}
+
+#[test]
+fn tagged_errors() {
+    let (path, message) = single(Shape::deserialize(&parse(r#"{"type": "Point"}"#)).unwrap_err());
+    assert_eq!(path, "type");
+    assert_eq!(
+        message,
+        r#"unknown variant `Point`, expected one of ["Circle", "Square", "point"]"#
+    );
+
+    let (path, message) = single(Shape::deserialize(&parse(r#"{"radius": 1}"#)).unwrap_err());
+    assert_eq!((path.as_str(), message.as_str()), ("type", "missing field"));
+
+    let (path, _) =
+        single(Shape::deserialize(&parse(r#"{"type": "Circle", "radius": "big"}"#)).unwrap_err());
+    assert_eq!(path, "radius");
+}
+
+#[test]
+fn malformed_schemas() {
+    for name in &["enum", "tagged_enum"] {
+        let setting = SettingType {
+            name: (*name).to_owned(),
+            params: HashMap::new(),
+        };
+        assert_eq!(setting_type(&setting), "unknown");
+        assert_eq!(setting_schema(&setting), JsonValue::new_object());
+    }
+
+    let mut params = HashMap::new();
+    params.insert("variants".to_owned(), JsonValue::from(3));
+    let setting = SettingType {
+        name: "tagged_enum".to_owned(),
+        params,
+    };
+    assert_eq!(setting_type(&setting), "unknown");
+    assert_eq!(setting_schema(&setting), JsonValue::new_object());
+}
@@ -0,0 +1,118 @@
+use vision_traits::error::{ErrorKind, Path, PathSegment};
+use vision_traits::types::range::RangeU8;
+use vision_traits::{Configurable, DeserializationError, Editable};
+
+#[derive(Editable, Debug)]
+#[allow(dead_code)]
+struct Threshold {
+    hue: RangeU8<0, 180>,
+    saturation: RangeU8<0, 255>,
+}
+
+#[derive(Configurable, Debug)]
+#[allow(dead_code)]
+struct Settings {
+    exposure: u8,
+    thresholds: Vec<Threshold>,
+    label: String,
+}
+
+fn errors(error: &DeserializationError) -> Vec<(String, &ErrorKind)> {
+    let mut errors = error
+        .errors()
+        .iter()
+        .map(|e| (e.path.to_string(), &e.kind))
+        .collect::<Vec<_>>();
+    errors.sort_by(|a, b| a.0.cmp(&b.0));
+    errors
+}
+
+#[test]
+fn path_display() {
+    let path = Path(vec![
+        PathSegment::Field("settings".to_owned()),
+        PathSegment::Field("thresholds".to_owned()),
+        PathSegment::Index(2),
+        PathSegment::Field("max".to_owned()),
+    ]);
+    assert_eq!(path.to_string(), "settings.thresholds[2].max");
+    assert_eq!(Path::default().to_string(), "$");
+}
+
+#[test]
+fn every_invalid_field_is_reported() {
+    let error = Settings::from_str(
+        r#"{
+            "exposure": 300,
+            "thresholds": [
+                {"hue": {"min": 0, "max": 10}, "saturation": {"min": 0, "max": 10}},
+                {"hue": {"min": 0, "max": 200}},
+                {"hue": {"min": 20, "max": 10}, "saturation": "high"}
+            ]
+        }"#,
+    )
+    .unwrap_err();
+
+    let errors = errors(&error);
+    let paths = errors.iter().map(|e| e.0.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "exposure",
+            "label",
+            "thresholds[1].hue.max",
+            "thresholds[1].saturation",
+            "thresholds[2].hue",
+            "thresholds[2].saturation",
+        ]
+    );
+    assert!(matches!(errors[0].1, ErrorKind::OutOfRange(_)));
+    assert!(matches!(errors[1].1, ErrorKind::Missing));
+    assert!(matches!(errors[2].1, ErrorKind::OutOfRange(_)));
+    assert!(matches!(errors[3].1, ErrorKind::Missing));
+    assert!(matches!(errors[4].1, ErrorKind::OutOfRange(_)));
+    assert!(matches!(errors[5].1, ErrorKind::WrongType(_)));
+}
+
+#[test]
+fn parse_error_position() {
+    let error = Settings::from_str("{\n  \"exposure\": 1,\n  \"label\": ?\n}").unwrap_err();
+    match errors(&error).as_slice() {
+        [(path, ErrorKind::Parse { line, column, .. })] => {
+            assert_eq!(path, "$");
+            assert_eq!((*line, *column), (3, 12));
+        }
+        other => panic!("unexpected errors: {:?}", other),
+    }
+}
+
+#[test]
+fn unexpected_end_of_input() {
+    let error = Settings::from_str("{\n  \"exposure\": 1,").unwrap_err();
+    match errors(&error).as_slice() {
+        [(_, ErrorKind::Parse { line, column, .. })] => assert_eq!((*line, *column), (2, 17)),
+        other => panic!("unexpected errors: {:?}", other),
+    }
+}
+
+#[test]
+fn messages() {
+    let error = Settings::from_str(r#"{"exposure": 1, "thresholds": []}"#).unwrap_err();
+    assert_eq!(error.to_string(), "label: missing field");
+
+    let error = Settings::from_str(r#"{"thresholds": [], "label": 3}"#).unwrap_err();
+    let mut lines = error
+        .to_string()
+        .lines()
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    lines[1..].sort();
+    assert_eq!(
+        lines,
+        [
+            "2 invalid values",
+            "  exposure: missing field",
+            "  label: expected string",
+        ]
+    );
+}
@@ -0,0 +1,120 @@
+use vision_traits::editable::Editable;
+use vision_traits::json::{self, array};
+use vision_traits::json_schema::{configurable_schema, setting_schema, DRAFT};
+use vision_traits::types::bounds::FloatBounds;
+use vision_traits::types::constrained::{ConstrainedF32, ConstrainedU8};
+use vision_traits::types::range::{RangeF64, RangeI32};
+use vision_traits::Configurable;
+
+struct Unit;
+impl FloatBounds<f32> for Unit {
+    const MIN: f32 = 0.0;
+    const MAX: f32 = 1.0;
+    const INCLUSIVE: bool = false;
+}
+
+struct Degrees;
+impl FloatBounds<f64> for Degrees {
+    const MIN: f64 = -90.0;
+    const MAX: f64 = 90.0;
+}
+
+#[derive(Configurable)]
+#[allow(dead_code)]
+struct Settings {
+    exposure: u8,
+    gain: f64,
+    #[name = "auto exposure"]
+    auto_exposure: bool,
+    label: String,
+    threshold: ConstrainedU8<10, 200, true>,
+    hue: RangeI32<-180, 180>,
+}
+
+#[test]
+fn object() {
+    let schema = configurable_schema::<Settings>();
+    assert_eq!(schema["$schema"], DRAFT);
+    assert_eq!(schema["type"], "object");
+    assert!(schema["additionalProperties"].is_null());
+    assert_eq!(
+        schema["required"],
+        array![
+            "auto exposure",
+            "exposure",
+            "gain",
+            "hue",
+            "label",
+            "threshold"
+        ]
+    );
+    assert_eq!(schema["properties"].len(), 6);
+}
+
+#[test]
+fn unknown_keys_are_allowed() {
+    let input = json::object! {
+        "exposure": 12,
+        "gain": 1.5,
+        "auto exposure": true,
+        "label": "front",
+        "threshold": 50,
+        "hue": { "min": -10, "max": 10, "step": 1 },
+        "removed": "setting",
+    };
+    assert!(Settings::deserialize(&input).is_ok());
+}
+
+#[test]
+fn primitives() {
+    let schema = configurable_schema::<Settings>();
+    let properties = &schema["properties"];
+    assert_eq!(properties["auto exposure"].dump(), r#"{"type":"boolean"}"#);
+    assert_eq!(properties["label"].dump(), r#"{"type":"string"}"#);
+    assert_eq!(
+        properties["exposure"].dump(),
+        r#"{"type":"integer","minimum":0,"maximum":255}"#
+    );
+    assert_eq!(properties["gain"].dump(), r#"{"type":"number"}"#);
+}
+
+#[test]
+fn bounds() {
+    let schema = setting_schema(&ConstrainedU8::<10, 200, true>::schema());
+    assert_eq!(
+        schema.dump(),
+        r#"{"type":"integer","minimum":10,"maximum":200}"#
+    );
+
+    let schema = setting_schema(&ConstrainedU8::<0, 100, false>::schema());
+    assert_eq!(
+        schema.dump(),
+        r#"{"type":"integer","exclusiveMinimum":0,"exclusiveMaximum":100}"#
+    );
+
+    let schema = setting_schema(&ConstrainedF32::<Unit>::schema());
+    assert_eq!(
+        schema.dump(),
+        r#"{"type":"number","exclusiveMinimum":0,"exclusiveMaximum":1}"#
+    );
+}
+
+#[test]
+fn ranges() {
+    let schema = setting_schema(&RangeI32::<-180, 180>::schema());
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], array!["min", "max"]);
+    assert!(schema["additionalProperties"].is_null());
+    for bound in &["min", "max"] {
+        assert_eq!(
+            schema["properties"][*bound].dump(),
+            r#"{"type":"integer","minimum":-180,"maximum":180}"#
+        );
+    }
+
+    let schema = setting_schema(&RangeF64::<Degrees>::schema());
+    assert_eq!(
+        schema["properties"]["min"].dump(),
+        r#"{"type":"number","minimum":-90,"maximum":90}"#
+    );
+}
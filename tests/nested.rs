@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use vision_traits::json::{self, JsonValue};
+use vision_traits::json_schema::{configurable_schema, setting_schema};
+use vision_traits::schema::SettingType;
+use vision_traits::typescript::setting_type;
+use vision_traits::{Configurable, Editable};
+
+#[derive(Editable, Debug, PartialEq)]
+struct Camera {
+    exposure: u8,
+    #[default = 1.0]
+    gain: f64,
+}
+
+#[derive(Editable, Debug, PartialEq)]
+struct Lens {
+    #[name = "focal length"]
+    focal_length: f64,
+}
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Settings {
+    camera: Camera,
+    lenses: Vec<Lens>,
+}
+
+#[test]
+fn nested_round_trip() {
+    let settings =
+        Settings::from_str(r#"{"camera": {"exposure": 12}, "lenses": [{"focal length": 35}]}"#)
+            .unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            camera: Camera {
+                exposure: 12,
+                gain: 1.0,
+            },
+            lenses: vec![Lens { focal_length: 35.0 }],
+        }
+    );
+    assert_eq!(
+        settings.serialize().dump(),
+        r#"{"camera":{"exposure":12,"gain":1},"lenses":[{"focal length":35}]}"#
+    );
+}
+
+#[test]
+fn nested_error_paths() {
+    let error = Settings::from_str(
+        r#"{"camera": {"exposure": 300, "gain": "high"}, "lenses": [{"focal length": 35}, {}]}"#,
+    )
+    .unwrap_err();
+    let mut errors = error
+        .errors()
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    errors.sort();
+    assert_eq!(
+        errors,
+        [
+            "camera.exposure: 300 is above the inclusive maximum of 255",
+            "camera.gain: expected f64",
+            "lenses[1].focal length: missing field",
+        ]
+    );
+}
+
+#[test]
+fn nested_json_schema() {
+    let schema = configurable_schema::<Settings>();
+    assert_eq!(
+        schema["properties"]["camera"].dump(),
+        r#"{"type":"object","properties":{"exposure":{"type":"integer","minimum":0,"maximum":255},"gain":{"type":"number","default":1}},"required":["exposure"]}"#
+    );
+    assert_eq!(
+        schema["properties"]["lenses"]["items"]["required"],
+        json::array!["focal length"]
+    );
+}
+
+#[test]
+fn nested_declarations() {
+    let schema = Settings::schema();
+    assert_eq!(
+        setting_type(&schema["camera"]),
+        "{ exposure: number; gain?: number }"
+    );
+    assert_eq!(
+        setting_type(&schema["lenses"]),
+        r#"Array<{ "focal length": number }>"#
+    );
+}
+
+#[test]
+fn object_without_fields() {
+    let setting = SettingType {
+        name: "object".to_owned(),
+        params: HashMap::new(),
+    };
+    assert_eq!(setting_type(&setting), "unknown");
+    assert_eq!(setting_schema(&setting), JsonValue::new_object());
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use common::node;
+use vision_traits::pipeline::{Edge, Pipeline, PipelineError, Port};
+use vision_traits::vision_node;
+
+#[vision_node]
+fn source() -> u32 {
+    3
+}
+
+#[vision_node]
+fn double(value: &u32) -> u32 {
+    value * 2
+}
+
+#[vision_node]
+fn increment(value: &u32) -> u32 {
+    value + 1
+}
+
+#[vision_node]
+fn add(a: &u32, b: &u32) -> u32 {
+    a + b
+}
+
+#[test]
+fn dependencies_run_first() {
+    let mut pipeline = Pipeline::new();
+    let sum = pipeline.add_node(node::<Add>());
+    let double = pipeline.add_node(node::<Double>());
+    let increment = pipeline.add_node(node::<Increment>());
+    let source = pipeline.add_node(node::<Source>());
+    pipeline.connect(double, "val", sum, "a").unwrap();
+    pipeline.connect(increment, "val", sum, "b").unwrap();
+    pipeline.connect(source, "val", double, "value").unwrap();
+    pipeline.connect(source, "val", increment, "value").unwrap();
+
+    let order = pipeline.topological_order().unwrap();
+    let position = |id| order.iter().position(|&e| e == id).unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(position(source) < position(double));
+    assert!(position(source) < position(increment));
+    assert!(position(double) < position(sum));
+    assert!(position(increment) < position(sum));
+
+    let outputs = pipeline.process_frame().unwrap();
+    assert_eq!(outputs[&sum]["val"].downcast_ref::<u32>(), Some(&10));
+}
+
+#[test]
+fn only_sinks_are_returned() {
+    let mut pipeline = Pipeline::new();
+    let source = pipeline.add_node(node::<Source>());
+    let double = pipeline.add_node(node::<Double>());
+    let increment = pipeline.add_node(node::<Increment>());
+    pipeline.connect(source, "val", double, "value").unwrap();
+    pipeline.connect(source, "val", increment, "value").unwrap();
+
+    let outputs = pipeline.process_frame().unwrap();
+    let mut sinks = outputs.keys().copied().collect::<Vec<_>>();
+    sinks.sort();
+    assert_eq!(sinks, [double, increment]);
+    assert_eq!(outputs[&double]["val"].downcast_ref::<u32>(), Some(&6));
+    assert_eq!(outputs[&increment]["val"].downcast_ref::<u32>(), Some(&4));
+}
+
+#[test]
+fn cycle() {
+    let mut pipeline = Pipeline::new();
+    let double = pipeline.add_node(node::<Double>());
+    let increment = pipeline.add_node(node::<Increment>());
+    pipeline.connect(double, "val", increment, "value").unwrap();
+    pipeline.connect(increment, "val", double, "value").unwrap();
+
+    assert!(matches!(
+        pipeline.topological_order(),
+        Err(PipelineError::Cycle)
+    ));
+    assert!(matches!(
+        pipeline.process_frame(),
+        Err(PipelineError::Cycle)
+    ));
+}
+
+#[test]
+fn input_already_connected() {
+    let mut pipeline = Pipeline::new();
+    let source = pipeline.add_node(node::<Source>());
+    let double = pipeline.add_node(node::<Double>());
+    pipeline.connect(source, "val", double, "value").unwrap();
+
+    match pipeline.connect(source, "val", double, "value") {
+        Err(PipelineError::InputAlreadyConnected(port)) => {
+            assert_eq!(port, Port::new(double, "value"))
+        }
+        other => panic!("expected a connected input, got {:?}", other),
+    }
+    assert_eq!(pipeline.edges().len(), 1);
+}
+
+#[test]
+fn unknown_node() {
+    let mut other = Pipeline::new();
+    other.add_node(node::<Source>());
+    let missing = other.add_node(node::<Source>());
+
+    let mut pipeline = Pipeline::new();
+    let double = pipeline.add_node(node::<Double>());
+    assert!(matches!(
+        pipeline.connect(missing, "val", double, "value"),
+        Err(PipelineError::UnknownNode(id)) if id == missing
+    ));
+    assert!(matches!(
+        pipeline.connect(double, "val", missing, "value"),
+        Err(PipelineError::UnknownNode(id)) if id == missing
+    ));
+    assert!(pipeline.edges().is_empty());
+}
+
+#[test]
+fn disconnect() {
+    let mut pipeline = Pipeline::new();
+    let source = pipeline.add_node(node::<Source>());
+    let double = pipeline.add_node(node::<Double>());
+    pipeline.connect(source, "val", double, "value").unwrap();
+    pipeline.process_frame().unwrap();
+
+    assert_eq!(
+        pipeline.disconnect(double, "value"),
+        Some(Edge {
+            from: Port::new(source, "val"),
+            to: Port::new(double, "value"),
+        })
+    );
+    assert_eq!(pipeline.disconnect(double, "value"), None);
+    assert!(pipeline.edges().is_empty());
+    assert!(matches!(
+        pipeline.process_frame(),
+        Err(PipelineError::InvalidConnections(_))
+    ));
+
+    pipeline.connect(source, "val", double, "value").unwrap();
+    let outputs = pipeline.process_frame().unwrap();
+    assert_eq!(outputs[&double]["val"].downcast_ref::<u32>(), Some(&6));
+}
@@ -0,0 +1,128 @@
+use std::any::Any;
+use std::collections::HashMap;
+use vision_traits::json::{self, JsonValue};
+use vision_traits::output::OutputSingular;
+use vision_traits::registry::{NodeRegistry, RegistryError};
+use vision_traits::{
+    register_node, vision_node, Configurable, DynErrResult, Node, NodeCreationError,
+};
+
+#[derive(Configurable)]
+struct ScaleSettings {
+    factor: u32,
+}
+
+#[vision_node]
+fn scale(settings: &ScaleSettings, value: &u32) -> u32 {
+    value * settings.factor
+}
+
+#[vision_node(name = "Scale")]
+fn other_scale(value: &u32) -> u32 {
+    *value
+}
+
+#[vision_node]
+fn negate(value: &i32) -> i32 {
+    -value
+}
+
+#[test]
+fn duplicate_node() {
+    let mut registry = NodeRegistry::new();
+    registry.register::<Scale>().unwrap();
+    assert!(matches!(
+        registry.register::<Scale>(),
+        Err(RegistryError::DuplicateNode(ref name)) if name == "Scale"
+    ));
+    assert!(matches!(
+        registry.register::<OtherScale>(),
+        Err(RegistryError::DuplicateNode(ref name)) if name == "Scale"
+    ));
+}
+
+#[test]
+fn unknown_node() {
+    let mut registry = NodeRegistry::new();
+    registry.register::<Scale>().unwrap();
+    assert!(!registry.contains("Blur"));
+    assert!(registry.get("Blur").is_none());
+    assert!(matches!(
+        registry.make("Blur", &JsonValue::new_object()),
+        Err(RegistryError::UnknownNode(ref name)) if name == "Blur"
+    ));
+}
+
+#[test]
+fn make_applies_settings() {
+    let mut registry = NodeRegistry::new();
+    registry.register::<Scale>().unwrap();
+    let mut node = registry
+        .make("Scale", &json::parse(r#"{"factor": 4}"#).unwrap())
+        .unwrap();
+
+    let value = 5u32;
+    let mut input = HashMap::<String, &dyn Any>::new();
+    input.insert("value".to_owned(), &value);
+    let output = node.process(&input).unwrap();
+    assert_eq!(output["val"].downcast_ref::<u32>(), Some(&20));
+
+    assert!(matches!(
+        registry.make("Scale", &JsonValue::new_object()),
+        Err(RegistryError::CreationError(
+            NodeCreationError::DeserializationError(_)
+        ))
+    ));
+}
+
+#[test]
+fn schemas_are_sorted() {
+    let mut registry = NodeRegistry::new();
+    registry.register::<Scale>().unwrap();
+    registry.register::<Negate>().unwrap();
+
+    let names = registry
+        .schemas()
+        .into_iter()
+        .map(|e| e.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Negate", "Scale"]);
+    assert_eq!(
+        registry.get("Scale").unwrap().schema().settings["factor"].name,
+        "u32"
+    );
+}
+
+struct Counter {
+    count: u32,
+}
+
+#[register_node]
+impl Node for Counter {
+    const NAME: &'static str = "Counter";
+
+    type S = ();
+    type I<'a> = ();
+    type O = OutputSingular<u32>;
+
+    fn make(_: Self::S) -> DynErrResult<Self> {
+        Ok(Self { count: 0 })
+    }
+
+    fn process(&mut self, _: Self::I<'_>) -> DynErrResult<Self::O> {
+        self.count += 1;
+        Ok(self.count.into())
+    }
+}
+
+#[test]
+fn linked_nodes() {
+    let registry = NodeRegistry::linked().unwrap();
+    assert!(registry.contains("Counter"));
+    assert!(!registry.contains("Scale"));
+
+    let mut node = registry.make("Counter", &JsonValue::Null).unwrap();
+    node.process(&HashMap::new()).unwrap();
+    let output = node.process(&HashMap::new()).unwrap();
+    assert_eq!(output["val"].downcast_ref::<u32>(), Some(&2));
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use vision_traits::editable::Editable;
+use vision_traits::error::ErrorKind;
+use vision_traits::json::{self, JsonValue};
+use vision_traits::schema::{Function, SettingType, Type};
+use vision_traits::types::constrained::ConstrainedU8;
+use vision_traits::types::range::RangeI32;
+use vision_traits::{Configurable, PortType};
+
+#[derive(Configurable)]
+#[allow(dead_code)]
+struct Settings {
+    exposure: u8,
+    gain: f64,
+    #[name = "auto exposure"]
+    auto_exposure: bool,
+    label: String,
+    threshold: ConstrainedU8<10, 200, true>,
+    hue: RangeI32<-180, 180>,
+}
+
+#[derive(PortType)]
+#[id = "my_crate.image"]
+#[description = "An 8-bit BGR image"]
+struct Image;
+
+#[derive(PortType)]
+#[id = "my_crate.mask"]
+struct Mask;
+
+#[derive(PortType)]
+#[id = "my_crate.marker"]
+#[name = "Fiducial marker"]
+struct Marker;
+
+#[derive(PortType)]
+#[id = "my_crate.contour"]
+struct Contour;
+
+fn function() -> Function {
+    let mut inputs = HashMap::new();
+    inputs.insert("image".to_owned(), Type::required::<Image>());
+    inputs.insert("mask".to_owned(), Type::optional::<Mask>());
+    inputs.insert("markers".to_owned(), Type::variadic::<Marker>());
+
+    let mut outputs = HashMap::new();
+    outputs.insert("contours".to_owned(), Type::required::<Vec<Contour>>());
+    outputs.insert("count".to_owned(), Type::required::<usize>());
+
+    Function {
+        name: "Find Contours".to_owned(),
+        settings: Settings::schema(),
+        inputs,
+        outputs,
+    }
+}
+
+#[test]
+fn type_round_trip() {
+    let ty = Type::optional::<u8>();
+    assert_eq!(Type::from_json(&ty.to_json()).unwrap(), ty);
+    let ty = Type::required::<Image>();
+    assert_eq!(Type::from_json(&ty.to_json()).unwrap(), ty);
+}
+
+#[test]
+fn type_wire_layout() {
+    assert_eq!(
+        Type::required::<u8>().to_json().dump(),
+        r#"{"id":"u8","name":"u8","required":true,"variadic":false}"#
+    );
+    assert_eq!(
+        Type::required::<Image>().to_json().dump(),
+        r#"{"id":"my_crate.image","name":"Image","description":"An 8-bit BGR image","required":true,"variadic":false}"#
+    );
+}
+
+#[test]
+fn type_required_by_default() {
+    let ty = Type::from_json(&json::parse(r#"{"id":"u8","name":"u8"}"#).unwrap()).unwrap();
+    assert_eq!(ty, Type::required::<u8>());
+}
+
+#[test]
+fn setting_type_round_trip() {
+    for (_, setting) in Settings::schema() {
+        assert_eq!(SettingType::from_json(&setting.to_json()).unwrap(), setting);
+    }
+}
+
+#[test]
+fn setting_type_wire_layout() {
+    let mut params = HashMap::new();
+    params.insert("min".to_owned(), JsonValue::from(10));
+    let setting = SettingType {
+        name: "ConstrainedU8".to_owned(),
+        params,
+    };
+
+    assert_eq!(
+        setting.to_json().dump(),
+        r#"{"name":"ConstrainedU8","params":{"min":10}}"#
+    );
+}
+
+#[test]
+fn function_round_trip() {
+    let function = function();
+    assert_eq!(Function::from_json(&function.to_json()).unwrap(), function);
+}
+
+#[test]
+fn function_round_trip_through_text() {
+    let function = function();
+    let text = function.to_json().dump();
+    let parsed = json::parse(&text).unwrap();
+    assert_eq!(Function::from_json(&parsed).unwrap(), function);
+}
+
+#[test]
+fn empty_function_round_trip() {
+    let function = Function {
+        name: "Empty".to_owned(),
+        settings: HashMap::new(),
+        inputs: HashMap::new(),
+        outputs: HashMap::new(),
+    };
+
+    assert_eq!(
+        function.to_json().dump(),
+        r#"{"name":"Empty","settings":{},"inputs":{},"outputs":{}}"#
+    );
+    assert_eq!(Function::from_json(&function.to_json()).unwrap(), function);
+}
+
+#[test]
+fn function_wire_layout() {
+    let json = function().to_json();
+    assert_eq!(json["name"], "Find Contours");
+    assert_eq!(json["inputs"]["image"]["id"], "my_crate.image");
+    assert_eq!(json["inputs"]["markers"]["name"], "Fiducial marker");
+    assert_eq!(json["inputs"]["mask"]["required"], false);
+    assert_eq!(json["inputs"]["markers"]["variadic"], true);
+    assert_eq!(json["outputs"]["contours"]["id"], "list<my_crate.contour>");
+    assert_eq!(json["outputs"]["contours"]["name"], "List<Contour>");
+    assert_eq!(json["outputs"]["count"]["id"], "usize");
+    assert_eq!(json["settings"]["auto exposure"]["name"], "bool");
+    assert_eq!(json["settings"]["threshold"]["name"], "ConstrainedU8");
+    assert_eq!(json["settings"]["hue"]["params"]["min"], -180);
+}
+
+#[test]
+fn constrained_bounds() {
+    let params = Settings::schema().remove("threshold").unwrap().params;
+    assert_eq!(params["min"], 10);
+    assert_eq!(params["max"], 200);
+    assert_eq!(params["inclusive"], true);
+
+    let params = ConstrainedU8::<0, 100, false>::schema().params;
+    assert_eq!(params["max"], 100);
+    assert_eq!(params["inclusive"], false);
+
+    let schema =
+        vision_traits::json_schema::setting_schema(&ConstrainedU8::<0, 100, false>::schema());
+    assert_eq!(schema["exclusiveMinimum"], 0);
+    assert_eq!(schema["exclusiveMaximum"], 100);
+}
+
+// The path and kind of the only error raised while reading `input`.
+fn single_error(input: &str) -> (String, ErrorKind) {
+    let error = Function::from_json(&json::parse(input).unwrap()).unwrap_err();
+    let mut errors = error.into_errors();
+    assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+    let error = errors.remove(0);
+    (error.path.to_string(), error.kind)
+}
+
+#[test]
+fn not_object() {
+    let (path, kind) = single_error("[]");
+    assert_eq!(path, "$");
+    assert!(matches!(kind, ErrorKind::WrongType(ref expected) if expected == "object"));
+}
+
+#[test]
+fn missing_field() {
+    let (path, kind) = single_error(r#"{"name": "Empty", "settings": {}, "inputs": {}}"#);
+    assert_eq!(path, "outputs");
+    assert!(matches!(kind, ErrorKind::Missing));
+}
+
+#[test]
+fn nested_missing_field() {
+    let (path, kind) = single_error(
+        r#"{"name": "Empty", "settings": {"gain": {"name": "f64"}}, "inputs": {}, "outputs": {}}"#,
+    );
+    assert_eq!(path, "settings.gain.params");
+    assert!(matches!(kind, ErrorKind::Missing));
+}
+
+#[test]
+fn nested_type_error() {
+    let (path, kind) = single_error(
+        r#"{"name": "Empty", "settings": {}, "inputs": {"image": {"id": "image", "name": 3}}, "outputs": {}}"#,
+    );
+    assert_eq!(path, "inputs.image.name");
+    assert!(matches!(kind, ErrorKind::WrongType(_)));
+}
+
+#[test]
+fn every_error_is_reported() {
+    let input = json::parse(r#"{"name": 1, "settings": {"a": {}}, "inputs": []}"#).unwrap();
+    let error = Function::from_json(&input).unwrap_err();
+    let mut paths = error
+        .errors()
+        .iter()
+        .map(|e| e.path.to_string())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(
+        paths,
+        [
+            "inputs",
+            "name",
+            "outputs",
+            "settings.a.name",
+            "settings.a.params"
+        ]
+    );
+}
+
+#[test]
+fn port_declarations() {
+    let declarations = vision_traits::typescript::declarations(&[function()]);
+    assert!(
+        declarations.contains("  image: unknown;"),
+        "{}",
+        declarations
+    );
+    assert!(
+        declarations.contains("  mask?: unknown;"),
+        "{}",
+        declarations
+    );
+    assert!(
+        declarations.contains("  markers?: Array<unknown>;"),
+        "{}",
+        declarations
+    );
+    assert!(
+        declarations.contains("  count: number;"),
+        "{}",
+        declarations
+    );
+    assert!(
+        declarations.contains("     * An 8-bit BGR image\n"),
+        "{}",
+        declarations
+    );
+}
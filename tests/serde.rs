@@ -0,0 +1,169 @@
+#![cfg(feature = "serde")]
+
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use vision_traits::editable::Editable;
+use vision_traits::json::JsonValue;
+use vision_traits::schema::{Function, SettingType, Type};
+use vision_traits::serde::Json;
+use vision_traits::types::bounds::FloatBounds;
+use vision_traits::types::constrained::{ConstrainedF32, ConstrainedU8};
+use vision_traits::types::range::{RangeF64, RangeI32};
+use vision_traits::Configurable;
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Settings {
+    exposure: u8,
+    gain: f64,
+    label: String,
+    threshold: ConstrainedU8<10, 200, true>,
+    hue: RangeI32<-180, 180>,
+    sizes: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Stage {
+    node: String,
+    #[serde(with = "vision_traits::serde")]
+    settings: Settings,
+}
+
+struct Unit;
+impl FloatBounds<f32> for Unit {
+    const MIN: f32 = 0.0;
+    const MAX: f32 = 1.0;
+}
+
+struct Degrees;
+impl FloatBounds<f64> for Degrees {
+    const MIN: f64 = -90.0;
+    const MAX: f64 = 90.0;
+}
+
+const STAGE: &str = r#"
+node = "threshold"
+
+[settings]
+exposure = 12
+gain = 1.5
+label = "camera"
+threshold = 100
+hue = { min = -20, max = 40 }
+sizes = [1, 2, 3]
+"#;
+
+fn function() -> Function {
+    let mut params = HashMap::new();
+    params.insert("min".to_owned(), 10.into());
+    params.insert("max".to_owned(), 200.into());
+    params.insert("inclusive".to_owned(), true.into());
+
+    let mut settings = HashMap::new();
+    settings.insert(
+        "threshold".to_owned(),
+        SettingType {
+            name: "ConstrainedU8".to_owned(),
+            params,
+        },
+    );
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "frame".to_owned(),
+        Type {
+            id: "opencv.mat".to_owned(),
+            name: "Mat".to_owned(),
+            description: None,
+            required: true,
+            variadic: false,
+        },
+    );
+
+    Function {
+        name: "threshold".to_owned(),
+        settings,
+        inputs,
+        outputs: HashMap::new(),
+    }
+}
+
+#[test]
+fn configurable_from_toml() {
+    let stage: Stage = toml::from_str(STAGE).unwrap();
+    assert_eq!(stage.node, "threshold");
+    assert_eq!(stage.settings.exposure, 12);
+    assert_eq!(stage.settings.gain, 1.5);
+    assert_eq!(stage.settings.label, "camera");
+    assert_eq!(stage.settings.threshold.serialize(), 100);
+    assert_eq!(
+        (stage.settings.hue.min(), stage.settings.hue.max()),
+        (-20, 40)
+    );
+    assert_eq!(stage.settings.sizes, vec![1, 2, 3]);
+}
+
+#[test]
+fn configurable_round_trip() {
+    let stage: Stage = toml::from_str(STAGE).unwrap();
+    let json = serde_json::to_string(&stage).unwrap();
+    assert_eq!(serde_json::from_str::<Stage>(&json).unwrap(), stage);
+}
+
+#[test]
+fn configurable_errors() {
+    let error = toml::from_str::<Stage>(&STAGE.replace("100", "201")).unwrap_err();
+    assert!(
+        error.to_string().contains("threshold: 201 is above"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn schema_round_trip() {
+    let function = function();
+    let json = serde_json::to_string(&function).unwrap();
+    assert_eq!(serde_json::from_str::<Function>(&json).unwrap(), function);
+
+    let ty = serde_json::to_value(&function.inputs["frame"]).unwrap();
+    assert_eq!(
+        ty,
+        serde_json::json!({ "id": "opencv.mat", "name": "Mat", "required": true, "variadic": false })
+    );
+    assert_eq!(
+        serde_json::from_value::<SettingType>(serde_json::json!({
+            "name": "ConstrainedU8",
+            "params": { "min": 10, "max": 200, "inclusive": true },
+        }))
+        .unwrap(),
+        function.settings["threshold"]
+    );
+}
+
+#[test]
+fn schema_errors() {
+    let error = serde_json::from_str::<Function>(r#"{"name": "blur"}"#).unwrap_err();
+    assert!(
+        error.to_string().starts_with("3 invalid values"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn bounded_types() {
+    let value: ConstrainedF32<Unit> = serde_json::from_str("0.25").unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), "0.25");
+    assert!(serde_json::from_str::<ConstrainedF32<Unit>>("1.5").is_err());
+
+    let range: RangeF64<Degrees> = toml::from_str("min = -45.0\nmax = 30.5").unwrap();
+    assert_eq!((range.min(), range.max()), (-45.0, 30.5));
+    assert!(toml::from_str::<RangeF64<Degrees>>("min = 10.0\nmax = -10.0").is_err());
+}
+
+#[test]
+fn json_values() {
+    let input = r#"{"a":[null,true,-3,18446744073709551615,0.5],"b":{"c":"d"}}"#;
+    let Json(value) = serde_json::from_str(input).unwrap();
+    assert_eq!(value["a"][3], JsonValue::from(u64::MAX));
+    assert_eq!(serde_json::to_string(&Json(value)).unwrap(), input);
+}
@@ -0,0 +1,283 @@
+use proptest::prelude::*;
+use std::collections::HashMap;
+use std::num::{NonZeroI64, NonZeroU32};
+use vision_traits::editable::Editable;
+use vision_traits::json;
+use vision_traits::types::bounds::FloatBounds;
+use vision_traits::types::constrained::{
+    ConstrainedF32, ConstrainedF64, ConstrainedI16, ConstrainedI64, ConstrainedU8,
+};
+use vision_traits::types::range::{RangeF64, RangeI32, RangeU16, RangeU64};
+use vision_traits::Configurable;
+
+#[derive(Configurable, Debug, Clone, PartialEq)]
+struct Settings {
+    exposure: u8,
+    offset: i32,
+    gain: f64,
+    #[name = "auto exposure"]
+    auto_exposure: bool,
+    label: String,
+    threshold: ConstrainedU8<10, 200, true>,
+    hue: RangeI32<-180, 180>,
+}
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Empty {}
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Unit;
+
+#[derive(Configurable, Debug, PartialEq)]
+struct Raw {
+    r#type: u8,
+    r#loop: bool,
+}
+
+struct Percent;
+impl FloatBounds<f64> for Percent {
+    const MIN: f64 = 0.0;
+    const MAX: f64 = 100.0;
+    const STEP: Option<f64> = Some(0.5);
+}
+
+struct Sigma;
+impl FloatBounds<f32> for Sigma {
+    const MIN: f32 = 0.0;
+    const MAX: f32 = 10.0;
+    const INCLUSIVE: bool = false;
+}
+
+fn round_trip<T: Editable + std::fmt::Debug + PartialEq>(value: T) {
+    let json = value.serialize();
+    assert_eq!(T::deserialize(&json).unwrap(), value);
+    let reparsed = vision_traits::json::parse(&json.dump()).unwrap();
+    assert_eq!(T::deserialize(&reparsed).unwrap(), value);
+}
+
+fn settings() -> impl Strategy<Value = Settings> {
+    (
+        any::<u8>(),
+        any::<i32>(),
+        -1e12..1e12f64,
+        any::<bool>(),
+        ".*",
+        10..=200u8,
+        (-180..=180i32, -180..=180i32),
+    )
+        .prop_map(
+            |(exposure, offset, gain, auto_exposure, label, threshold, (min, max))| Settings {
+                exposure,
+                offset,
+                gain,
+                auto_exposure,
+                label,
+                threshold: ConstrainedU8::new(threshold).unwrap(),
+                hue: RangeI32::new(min.min(max), min.max(max)).unwrap(),
+            },
+        )
+}
+
+proptest! {
+    #[test]
+    fn u8_round_trip(value: u8) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn u16_round_trip(value: u16) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn u32_round_trip(value: u32) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn i8_round_trip(value: i8) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn i16_round_trip(value: i16) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn i32_round_trip(value: i32) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn u64_round_trip(value: u64) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn usize_round_trip(value: usize) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn i64_round_trip(value: i64) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn isize_round_trip(value: isize) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn nonzero_round_trip(unsigned: NonZeroU32, signed: NonZeroI64) {
+        round_trip(unsigned);
+        round_trip(signed);
+    }
+
+    #[test]
+    fn f32_round_trip(value in proptest::num::f32::NORMAL | proptest::num::f32::ZERO) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn f64_round_trip(value in proptest::num::f64::NORMAL | proptest::num::f64::ZERO) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn bool_round_trip(value: bool) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn string_round_trip(value: String) {
+        round_trip(value);
+    }
+
+    #[test]
+    fn constrained_round_trip(value in 10..=200u8, signed in -5..=5i16) {
+        round_trip(ConstrainedU8::<10, 200, true>::new(value).unwrap());
+        round_trip(ConstrainedI16::<-5, 5, true>::new(signed).unwrap());
+    }
+
+    #[test]
+    fn range_round_trip(min in -180..=180i32, max in -180..=180i32, unsigned in 0..=1000u16) {
+        round_trip(RangeI32::<-180, 180>::new(min.min(max), min.max(max)).unwrap());
+        round_trip(RangeU16::<0, 1000>::new(unsigned, unsigned).unwrap());
+    }
+
+    #[test]
+    fn composite_round_trip(
+        option: Option<u8>,
+        list: Vec<(i16, bool)>,
+        array: [u32; 3],
+        map: HashMap<String, Vec<i8>>,
+    ) {
+        round_trip(option);
+        round_trip(list);
+        round_trip(array);
+        round_trip(map);
+    }
+
+    #[test]
+    fn float_constrained_round_trip(percent in 0.0..=100.0f64, sigma in 0.001..9.999f32) {
+        round_trip(ConstrainedF64::<Percent>::new(percent).unwrap());
+        round_trip(ConstrainedF32::<Sigma>::new(sigma).unwrap());
+        round_trip(RangeF64::<Percent>::new(percent, 100.0).unwrap());
+    }
+
+    #[test]
+    fn configurable_round_trip(settings in settings()) {
+        let json = settings.serialize();
+        prop_assert_eq!(Settings::deserialize(&json).unwrap(), settings.clone());
+        prop_assert_eq!(Settings::from_str(&json.dump()).unwrap(), settings.clone());
+        prop_assert_eq!(json["auto exposure"].as_bool(), Some(settings.auto_exposure));
+    }
+}
+
+#[test]
+fn empty_configurable_round_trip() {
+    assert_eq!(Empty {}.serialize().dump(), "{}");
+    assert_eq!(Empty::deserialize(&Empty {}.serialize()).unwrap(), Empty {});
+    assert_eq!(Unit.serialize().dump(), "{}");
+    assert_eq!(Unit::deserialize(&Unit.serialize()).unwrap(), Unit);
+}
+
+#[test]
+fn raw_field_names() {
+    let raw = Raw {
+        r#type: 3,
+        r#loop: true,
+    };
+    let mut keys = raw
+        .serialize()
+        .entries()
+        .map(|(key, _)| key.to_owned())
+        .collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, ["loop", "type"]);
+    assert_eq!(Raw::from_str(r#"{"type": 3, "loop": true}"#).unwrap(), raw);
+    assert!(Raw::schema().contains_key("type"));
+}
+
+#[test]
+fn range_layout() {
+    let value = RangeI32::<-180, 180>::new(-10, 20).unwrap();
+    assert_eq!(value.serialize().dump(), r#"{"min":-10,"max":20}"#);
+}
+
+#[test]
+fn bounded_extremes_round_trip() {
+    round_trip(ConstrainedI64::<{ i64::MIN }, { i64::MAX }, true>::new(i64::MIN).unwrap());
+    round_trip(RangeU64::<0, { u64::MAX }>::new(0, u64::MAX).unwrap());
+}
+
+#[test]
+fn integer_extremes_layout() {
+    assert_eq!(u64::MAX.serialize().dump(), "18446744073709551615");
+    assert_eq!(i64::MIN.serialize().dump(), "-9223372036854775808");
+    assert_eq!(u64::schema().params["max"].dump(), "18446744073709551615");
+    assert_eq!(i64::schema().params["min"].dump(), "-9223372036854775808");
+    assert_eq!(NonZeroU32::schema().params["min"].dump(), "1");
+    assert_eq!(0.1f32.serialize().dump(), "0.1");
+}
+
+#[test]
+fn zero_is_not_nonzero() {
+    assert!(NonZeroU32::deserialize(&0.into()).is_err());
+    assert!(u8::deserialize(&1.5.into()).is_err());
+    assert_eq!(u8::deserialize(&json::parse("2.0").unwrap()).unwrap(), 2);
+}
+
+#[test]
+fn float_overflow_is_rejected() {
+    for input in &["1e39", "-1e39"] {
+        assert!(f32::deserialize(&json::parse(input).unwrap()).is_err());
+    }
+    assert!(f64::deserialize(&json::parse("1e400").unwrap()).is_err());
+    assert_eq!(
+        f64::deserialize(&json::parse("1e39").unwrap()).unwrap(),
+        1e39
+    );
+    assert_eq!(
+        f32::deserialize(&json::parse("3.4028235e38").unwrap()).unwrap(),
+        f32::MAX
+    );
+}
+
+#[test]
+fn float_bounds() {
+    assert!(ConstrainedF64::<Percent>::deserialize(&100.0.into()).is_ok());
+    assert!(ConstrainedF64::<Percent>::deserialize(&100.5.into()).is_err());
+    assert!(ConstrainedF32::<Sigma>::deserialize(&0.0.into()).is_err());
+    assert!(ConstrainedF32::<Sigma>::deserialize(&10.0.into()).is_err());
+
+    let params = ConstrainedF32::<Sigma>::schema().params;
+    assert_eq!(params["inclusive"], false);
+    assert!(!params.contains_key("step"));
+    assert_eq!(
+        ConstrainedF64::<Percent>::schema().params["step"].dump(),
+        "0.5"
+    );
+}
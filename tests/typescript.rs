@@ -0,0 +1,70 @@
+use vision_traits::types::bounds::FloatBounds;
+use vision_traits::types::constrained::{ConstrainedF32, ConstrainedU8};
+use vision_traits::typescript::linked_declarations;
+use vision_traits::{vision_node, Configurable};
+
+struct Opacity;
+impl FloatBounds<f32> for Opacity {
+    const MIN: f32 = 0.0;
+    const MAX: f32 = 1.0;
+    const INCLUSIVE: bool = false;
+    const STEP: Option<f32> = Some(0.05);
+}
+
+#[derive(Configurable)]
+struct BlurSettings {
+    #[name = "kernel size"]
+    kernel_size: ConstrainedU8<1, 31, true>,
+    #[default = 1.5]
+    sigma: f64,
+    opacity: ConstrainedF32<Opacity>,
+    #[name = "$label"]
+    #[default]
+    label: String,
+}
+
+#[allow(clippy::ptr_arg)]
+#[vision_node(name = "gaussian-blur", register)]
+fn blur(settings: &BlurSettings, image: &Vec<f64>) -> Vec<f64> {
+    image.iter().map(|e| e / settings.sigma).collect()
+}
+
+#[test]
+fn linked_settings() {
+    let declarations = linked_declarations().unwrap();
+    assert!(
+        declarations.contains(
+            r#"/** Settings of the `gaussian-blur` node. */
+export interface GaussianBlurSettings {
+    /** @default "" */
+    $label?: string;
+    /**
+     * @minimum 1
+     * @maximum 31
+     */
+    "kernel size": number;
+    /**
+     * @exclusiveMinimum 0
+     * @exclusiveMaximum 1
+     * @step 0.05
+     */
+    opacity: number;
+    /** @default 1.5 */
+    sigma?: number;
+}
+"#
+        ),
+        "{}",
+        declarations
+    );
+    assert!(
+        declarations.contains(
+            r#"export interface GaussianBlurInputs {
+    /** List<f64> (`list<f64>`) */
+    image: Array<number>;
+}"#
+        ),
+        "{}",
+        declarations
+    );
+}
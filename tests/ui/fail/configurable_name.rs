@@ -0,0 +1,9 @@
+use vision_traits::Configurable;
+
+#[derive(Configurable)]
+struct Settings {
+    #[name(exposure)]
+    exposure: u8,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use vision_traits::Configurable;
+
+#[derive(Configurable)]
+struct Settings(u8);
+
+fn main() {}
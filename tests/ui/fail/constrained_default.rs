@@ -0,0 +1,5 @@
+use vision_traits::types::constrained::ConstrainedU8;
+
+fn main() {
+    let _ = ConstrainedU8::<0, 100, false>::default();
+}
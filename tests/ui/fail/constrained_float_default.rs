@@ -0,0 +1,13 @@
+use vision_traits::types::bounds::FloatBounds;
+use vision_traits::types::constrained::ConstrainedF64;
+
+struct Ratio;
+impl FloatBounds<f64> for Ratio {
+    const MIN: f64 = 0.0;
+    const MAX: f64 = 1.0;
+    const INCLUSIVE: bool = false;
+}
+
+fn main() {
+    let _ = ConstrainedF64::<Ratio>::default();
+}
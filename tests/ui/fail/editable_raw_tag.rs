@@ -0,0 +1,9 @@
+use vision_traits::Editable;
+
+#[derive(Editable)]
+enum Shape {
+    Circle { radius: f64 },
+    Polygon { r#type: String, sides: u8 },
+}
+
+fn main() {}
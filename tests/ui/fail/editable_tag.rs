@@ -0,0 +1,15 @@
+use vision_traits::Editable;
+
+#[derive(Editable)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    Polygon {
+        #[name = "type"]
+        kind: String,
+        sides: u8,
+    },
+}
+
+fn main() {}
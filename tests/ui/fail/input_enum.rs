@@ -0,0 +1,8 @@
+use vision_traits::Input;
+
+#[derive(Input)]
+enum Inputs {
+    Image,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use vision_traits::Input;
+
+#[derive(Input)]
+#[input_lifetime('b)]
+struct Inputs<'a> {
+    image: &'a Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use vision_traits::Input;
+
+#[derive(Input)]
+struct Inputs<'a> {
+    image: &'a Vec<u8>,
+    threshold: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use vision_traits::Input;
+
+#[derive(Input)]
+struct Inputs<'a> {
+    images: Vec<&'a Vec<u8>>,
+    #[variadic]
+    masks: &'a Vec<bool>,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use vision_traits::Output;
+
+#[derive(Output)]
+struct Outputs(Vec<u8>);
+
+fn main() {}
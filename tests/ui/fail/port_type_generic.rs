@@ -0,0 +1,7 @@
+use vision_traits::PortType;
+
+#[derive(PortType)]
+#[id = "my_crate.wrapper"]
+struct Wrapper<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use vision_traits::PortType;
+
+#[derive(PortType)]
+#[id("image")]
+struct Image;
+
+fn main() {}
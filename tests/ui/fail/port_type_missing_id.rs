@@ -0,0 +1,6 @@
+use vision_traits::PortType;
+
+#[derive(PortType)]
+struct Image;
+
+fn main() {}
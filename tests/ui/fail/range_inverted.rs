@@ -0,0 +1,6 @@
+use vision_traits::editable::Editable;
+use vision_traits::types::range::RangeI32;
+
+fn main() {
+    let _ = RangeI32::<10, -10>::schema();
+}
@@ -0,0 +1,23 @@
+use std::marker::PhantomData;
+use vision_traits::register_node;
+
+struct Passthrough<T>(PhantomData<T>);
+
+#[register_node]
+impl<T: 'static> vision_traits::Node for Passthrough<T> {
+    const NAME: &'static str = "Passthrough";
+
+    type S = ();
+    type I<'a> = ();
+    type O = ();
+
+    fn make(_: Self::S) -> vision_traits::DynErrResult<Self> {
+        Ok(Self(PhantomData))
+    }
+
+    fn process(&mut self, _: Self::I<'_>) -> vision_traits::DynErrResult<Self::O> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use vision_traits::register_node;
+
+struct Counter;
+
+#[register_node]
+impl Counter {
+    fn new() -> Self {
+        Self
+    }
+}
+
+#[register_node]
+impl Default for Counter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use vision_traits::vision_node;
+
+#[vision_node(name = 3)]
+fn named(value: &u32) -> u32 {
+    *value
+}
+
+#[vision_node(register = true)]
+fn registered(value: &u32) -> u32 {
+    *value
+}
+
+#[vision_node]
+fn generic<T>(value: &T) -> u32 {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use vision_traits::vision_node;
+
+#[vision_node]
+fn owned(value: u32) -> u32 {
+    value
+}
+
+#[vision_node]
+fn mutable(value: &mut u32) -> u32 {
+    *value
+}
+
+#[vision_node]
+fn owned_settings(settings: u32, value: &u32) -> u32 {
+    settings + value
+}
+
+#[vision_node]
+fn destructured((a, b): (&u32, &u32)) -> u32 {
+    a + b
+}
+
+struct Counter;
+
+impl Counter {
+    #[vision_node]
+    fn count(&self, value: &u32) -> u32 {
+        *value
+    }
+}
+
+fn main() {}
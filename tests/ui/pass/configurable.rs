@@ -0,0 +1,26 @@
+use vision_traits::json::object;
+use vision_traits::Configurable;
+
+#[derive(Configurable)]
+struct Named {
+    exposure: u8,
+    #[name = "auto exposure"]
+    auto_exposure: bool,
+}
+
+#[derive(Configurable)]
+struct Empty {}
+
+#[derive(Configurable)]
+struct Unit;
+
+fn main() {
+    let named = Named::deserialize(&object! { "exposure": 3, "auto exposure": true }).unwrap();
+    assert_eq!(named.exposure, 3);
+    assert!(named.auto_exposure);
+    assert_eq!(Named::schema().len(), 2);
+
+    assert!(Empty::schema().is_empty());
+    Empty::deserialize(&object! {}).unwrap();
+    Unit::deserialize(&object! {}).unwrap();
+}
@@ -0,0 +1,78 @@
+use std::any::Any;
+use std::collections::HashMap;
+use vision_traits::input::Input;
+use vision_traits::Input;
+
+#[derive(Input)]
+struct Named<'a> {
+    image: &'a Vec<u8>,
+    #[name = "threshold value"]
+    threshold: &'a u8,
+    mask: Option<&'a Vec<bool>>,
+    #[variadic]
+    #[name = "hint"]
+    hints: Vec<&'a u32>,
+}
+
+#[derive(Input)]
+#[input_lifetime('input)]
+struct Attributed<'input> {
+    image: &'input Vec<u8>,
+}
+
+#[derive(Input)]
+struct Empty {}
+
+#[derive(Input)]
+struct Unit;
+
+fn main() {
+    let image = vec![1u8, 2, 3];
+    let threshold = 7u8;
+    let mut map: HashMap<String, &dyn Any> = HashMap::new();
+    map.insert("image".to_owned(), &image);
+    map.insert("threshold value".to_owned(), &threshold);
+
+    let named = Named::from_any_map(&map).unwrap();
+    assert_eq!(named.image, &image);
+    assert_eq!(*named.threshold, 7);
+    assert!(named.mask.is_none());
+    assert!(named.hints.is_empty());
+    assert_eq!(Named::schema()["threshold value"].name, "u8");
+    assert!(Named::schema()["threshold value"].required);
+    assert!(!Named::schema()["mask"].required);
+    assert!(Named::schema()["hint"].variadic);
+
+    let mask = vec![true, false];
+    map.insert("mask".to_owned(), &mask);
+    assert_eq!(Named::from_any_map(&map).unwrap().mask, Some(&mask));
+    map.insert("mask".to_owned(), &threshold);
+    assert!(Named::from_any_map(&map).is_err());
+    map.insert("mask".to_owned(), &mask);
+
+    // Slots are read in index order, skipping disconnected ones.
+    let (first, third, tenth) = (1u32, 3u32, 10u32);
+    map.insert("hint.10".to_owned(), &tenth);
+    map.insert("hint.0".to_owned(), &first);
+    map.insert("hint.2".to_owned(), &third);
+    map.insert("hints.1".to_owned(), &threshold);
+    assert_eq!(Named::from_any_map(&map).unwrap().hints, vec![&1, &3, &10]);
+
+    map.insert("hint.1".to_owned(), &threshold);
+    let error = match Named::from_any_map(&map) {
+        Ok(_) => panic!("hint.1 is not a u32"),
+        Err(error) => error,
+    };
+    assert_eq!(error.errors()[0].path.to_string(), "hint[1]");
+
+    let attributed = Attributed::from_any_map(&map).unwrap();
+    assert_eq!(attributed.image.len(), 3);
+    assert_eq!(Attributed::schema().len(), 1);
+
+    Empty::from_any_map(&map).unwrap();
+    Unit::from_any_map(&map).unwrap();
+    assert!(Empty::schema().is_empty() && Unit::schema().is_empty());
+
+    map.remove("image");
+    assert!(Named::from_any_map(&map).is_err());
+}
@@ -0,0 +1,32 @@
+use vision_traits::output::Output;
+use vision_traits::Output;
+
+#[derive(Output)]
+struct Named {
+    contours: Vec<u32>,
+    #[name = "largest area"]
+    largest_area: f64,
+}
+
+#[derive(Output)]
+struct Empty {}
+
+#[derive(Output)]
+struct Unit;
+
+fn main() {
+    let output = Named {
+        contours: vec![1, 2],
+        largest_area: 4.5,
+    };
+    let map = output.to_any_map();
+    assert_eq!(
+        map["contours"].downcast_ref::<Vec<u32>>(),
+        Some(&vec![1, 2])
+    );
+    assert_eq!(map["largest area"].downcast_ref::<f64>(), Some(&4.5));
+    assert_eq!(Named::schema()["largest area"].name, "f64");
+
+    assert!(Empty {}.to_any_map().is_empty() && Empty::schema().is_empty());
+    assert!(Unit.to_any_map().is_empty() && Unit::schema().is_empty());
+}
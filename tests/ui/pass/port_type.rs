@@ -0,0 +1,32 @@
+use vision_traits::port::PortType;
+use vision_traits::schema::Type;
+use vision_traits::PortType;
+
+#[derive(PortType)]
+#[id = "my_crate.image"]
+struct Image;
+
+#[derive(PortType)]
+#[id = "my_crate.contour"]
+#[name = "Contour"]
+#[description = "A closed outline of a detected shape"]
+struct Outline {
+    _points: Vec<(i32, i32)>,
+}
+
+fn main() {
+    assert_eq!(Image::id(), "my_crate.image");
+    assert_eq!(Image::name(), "Image");
+    assert_eq!(Image::description(), None);
+
+    assert_eq!(Outline::id(), "my_crate.contour");
+    assert_eq!(Outline::name(), "Contour");
+    assert_eq!(
+        Outline::description().as_deref(),
+        Some("A closed outline of a detected shape")
+    );
+
+    let ty = Type::variadic::<Vec<Outline>>();
+    assert_eq!(ty.id, "list<my_crate.contour>");
+    assert_eq!(ty.name, "List<Contour>");
+}
@@ -0,0 +1,29 @@
+use vision_traits::registry::NodeRegistry;
+use vision_traits::{register_node, DynErrResult, Node};
+
+struct Counter {
+    count: u32,
+}
+
+#[register_node]
+impl Node for Counter {
+    const NAME: &'static str = "Counter";
+
+    type S = ();
+    type I<'a> = ();
+    type O = ();
+
+    fn make(_: Self::S) -> DynErrResult<Self> {
+        Ok(Self { count: 0 })
+    }
+
+    fn process(&mut self, _: Self::I<'_>) -> DynErrResult<Self::O> {
+        self.count += 1;
+        Ok(())
+    }
+}
+
+fn main() {
+    let registry = NodeRegistry::linked().unwrap();
+    assert!(registry.contains("Counter"));
+}
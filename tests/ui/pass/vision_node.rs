@@ -0,0 +1,50 @@
+use std::any::Any;
+use std::collections::HashMap;
+use vision_traits::input::Input;
+use vision_traits::{vision_node, Node};
+
+#[vision_node]
+fn apply_mask(image: &Vec<u8>, mask: Option<&Vec<bool>>) -> Vec<u8> {
+    match mask {
+        Some(mask) => image
+            .iter()
+            .zip(mask)
+            .map(|(&pixel, &keep)| if keep { pixel } else { 0 })
+            .collect(),
+        None => image.clone(),
+    }
+}
+
+#[vision_node]
+fn brightest(images: Vec<&Vec<u8>>) -> u8 {
+    images
+        .iter()
+        .flat_map(|e| e.iter())
+        .copied()
+        .max()
+        .unwrap_or(0)
+}
+
+fn main() {
+    let image = vec![1u8, 2, 3];
+    let mut map: HashMap<String, &dyn Any> = HashMap::new();
+    map.insert("image".to_owned(), &image);
+
+    let mut node = ApplyMask::make(()).unwrap();
+    let input = ApplyMaskInput::from_any_map(&map).unwrap();
+    assert_eq!(node.process(input).unwrap().val, vec![1, 2, 3]);
+
+    let mask = vec![true, false, true];
+    map.insert("mask".to_owned(), &mask);
+    let input = ApplyMaskInput::from_any_map(&map).unwrap();
+    assert_eq!(node.process(input).unwrap().val, vec![1, 0, 3]);
+    assert!(!ApplyMaskInput::schema()["mask"].required);
+
+    let other = vec![9u8];
+    map.insert("images.0".to_owned(), &image);
+    map.insert("images.1".to_owned(), &other);
+    let mut node = Brightest::make(()).unwrap();
+    let input = BrightestInput::from_any_map(&map).unwrap();
+    assert_eq!(node.process(input).unwrap().val, 9);
+    assert!(BrightestInput::schema()["images"].variadic);
+}
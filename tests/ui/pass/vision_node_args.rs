@@ -0,0 +1,69 @@
+use std::any::Any;
+use std::collections::HashMap;
+use vision_traits::registry::NodeRegistry;
+use vision_traits::{vision_node, Configurable, DynErrResult, Node, NodeProcessable};
+
+#[derive(Configurable)]
+struct DivideSettings {
+    divisor: u32,
+}
+
+#[vision_node(name = "integer-divide", register)]
+fn divide(settings: &DivideSettings, value: &u32) -> DynErrResult<u32> {
+    if settings.divisor == 0 {
+        return Err("division by zero".into());
+    }
+    Ok(value / settings.divisor)
+}
+
+#[vision_node(register)]
+fn checked_negate(value: &i32) -> Result<i32, &'static str> {
+    value.checked_neg().ok_or("overflow")
+}
+
+#[vision_node]
+fn log(value: &u32) -> Result<(), String> {
+    if *value > 100 {
+        Err(format!("{} is too large", value))
+    } else {
+        Ok(())
+    }
+}
+
+fn main() {
+    assert_eq!(Divide::NAME, "integer-divide");
+    assert_eq!(CheckedNegate::NAME, "CheckedNegate");
+
+    let registry = NodeRegistry::linked().unwrap();
+    assert!(registry.contains("integer-divide"));
+    assert!(registry.contains("CheckedNegate"));
+    assert!(!registry.contains("Log"));
+
+    let value = 7u32;
+    let mut input = HashMap::<String, &dyn Any>::new();
+    input.insert("value".to_owned(), &value);
+
+    let mut node = registry
+        .make(
+            "integer-divide",
+            &vision_traits::json::parse(r#"{"divisor": 2}"#).unwrap(),
+        )
+        .unwrap();
+    let output = node.process(&input).unwrap();
+    assert_eq!(output["val"].downcast_ref::<u32>(), Some(&3));
+
+    let mut node = registry
+        .make(
+            "integer-divide",
+            &vision_traits::json::parse(r#"{"divisor": 0}"#).unwrap(),
+        )
+        .unwrap();
+    assert!(node.process(&input).is_err());
+
+    let mut node = <Log as NodeProcessable>::make(&vision_traits::json::JsonValue::Null).unwrap();
+    assert!(node.process(&input).unwrap().is_empty());
+    let value = 101u32;
+    input.insert("value".to_owned(), &value);
+    assert!(node.process(&input).is_err());
+    assert!(Log::get_schema().outputs.is_empty());
+}
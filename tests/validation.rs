@@ -0,0 +1,385 @@
+use vision_traits::editable::Editable;
+use vision_traits::error::ErrorKind;
+use vision_traits::json::{self, JsonValue};
+use vision_traits::types::bounds::FloatBounds;
+use vision_traits::types::constrained::{
+    ConstrainedF32, ConstrainedF64, ConstrainedI8, ConstrainedU8,
+};
+use vision_traits::types::range::{RangeF32, RangeF64, RangeI32};
+use vision_traits::{Configurable, DeserializationError, ValidationError};
+
+#[derive(Configurable, Debug)]
+#[allow(dead_code)]
+struct Settings {
+    threshold: ConstrainedU8<10, 200, true>,
+    hue: RangeI32<-180, 180>,
+}
+
+struct Ratio;
+impl FloatBounds<f64> for Ratio {
+    const MIN: f64 = 0.0;
+    const MAX: f64 = 1.0;
+    const INCLUSIVE: bool = false;
+}
+
+struct Gain;
+impl FloatBounds<f32> for Gain {
+    const MIN: f32 = 1.0;
+    const MAX: f32 = 16.0;
+}
+
+fn parse(input: &str) -> JsonValue {
+    json::parse(input).unwrap()
+}
+
+// The path and kind of the only error in `error`.
+fn single(error: DeserializationError) -> (String, ErrorKind) {
+    let mut errors = error.into_errors();
+    assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+    let error = errors.remove(0);
+    (error.path.to_string(), error.kind)
+}
+
+fn out_of_range(error: DeserializationError) -> (String, ValidationError) {
+    match single(error) {
+        (path, ErrorKind::OutOfRange(error)) => (path, error),
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+fn wrong_type(error: DeserializationError) -> String {
+    match single(error) {
+        (path, ErrorKind::WrongType(_)) => path,
+        other => panic!("expected a type error, got {:?}", other),
+    }
+}
+
+fn below(value: &str, bound: &str, inclusive: bool) -> ValidationError {
+    ValidationError::BelowMinimum {
+        value: value.to_owned(),
+        bound: bound.to_owned(),
+        inclusive,
+    }
+}
+
+fn above(value: &str, bound: &str, inclusive: bool) -> ValidationError {
+    ValidationError::AboveMaximum {
+        value: value.to_owned(),
+        bound: bound.to_owned(),
+        inclusive,
+    }
+}
+
+fn inverted(min: &str, max: &str) -> ValidationError {
+    ValidationError::InvertedRange {
+        min: min.to_owned(),
+        max: max.to_owned(),
+    }
+}
+
+#[test]
+fn string_wrong_type() {
+    assert!(String::deserialize(&parse("12")).is_err());
+}
+
+#[test]
+fn constrained_below_minimum() {
+    let error = ConstrainedU8::<10, 200, true>::deserialize(&parse("9")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), below("9", "10", true))
+    );
+}
+
+#[test]
+fn constrained_above_maximum() {
+    let error = ConstrainedU8::<10, 200, true>::deserialize(&parse("201")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), above("201", "200", true))
+    );
+}
+
+#[test]
+fn constrained_exclusive_bounds() {
+    let error = ConstrainedU8::<10, 200, false>::deserialize(&parse("10")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), below("10", "10", false))
+    );
+
+    let error = ConstrainedU8::<10, 200, false>::deserialize(&parse("200")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), above("200", "200", false))
+    );
+
+    assert!(ConstrainedU8::<10, 200, false>::deserialize(&parse("11")).is_ok());
+    assert!(ConstrainedU8::<10, 200, true>::deserialize(&parse("10")).is_ok());
+}
+
+#[test]
+fn constrained_outside_type() {
+    let error = ConstrainedU8::<10, 200, true>::deserialize(&parse("300")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), above("300", "200", true))
+    );
+
+    let error = ConstrainedI8::<-5, 5, true>::deserialize(&parse("-1000")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), below("-1000", "-5", true))
+    );
+}
+
+#[test]
+fn constrained_new() {
+    assert_eq!(ConstrainedU8::<10, 200, false>::new(11).unwrap().get(), 11);
+    assert_eq!(
+        ConstrainedU8::<10, 200, false>::new(10).unwrap_err(),
+        below("10", "10", false)
+    );
+    assert_eq!(
+        ConstrainedI8::<-5, 5, true>::new(6).unwrap_err(),
+        above("6", "5", true)
+    );
+}
+
+#[test]
+fn constrained_default() {
+    assert_eq!(ConstrainedU8::<10, 200, true>::default().get(), 10);
+    assert_eq!(ConstrainedI8::<-5, 5, true>::default().get(), -5);
+}
+
+#[test]
+fn integer_outside_type() {
+    let error = u8::deserialize(&parse("300")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), above("300", "255", true))
+    );
+
+    let error = i64::deserialize(&parse("-9223372036854775809")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        (
+            "$".to_owned(),
+            below("-9223372036854775809", "-9223372036854775808", true)
+        )
+    );
+
+    assert_eq!(wrong_type(u8::deserialize(&parse("1.5")).unwrap_err()), "$");
+}
+
+#[test]
+fn constrained_wrong_type() {
+    for input in &["\"12\"", "12.5", "null", "[]"] {
+        let error = ConstrainedU8::<10, 200, true>::deserialize(&parse(input)).unwrap_err();
+        assert_eq!(wrong_type(error), "$");
+    }
+}
+
+#[test]
+fn constrained_float_exclusive_bounds() {
+    let error = ConstrainedF64::<Ratio>::deserialize(&parse("0")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), below("0", "0", false))
+    );
+
+    let error = ConstrainedF64::<Ratio>::deserialize(&parse("1")).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), above("1", "1", false))
+    );
+
+    assert!(ConstrainedF64::<Ratio>::deserialize(&parse("0.5")).is_ok());
+}
+
+#[test]
+fn constrained_float_new() {
+    assert_eq!(ConstrainedF64::<Ratio>::new(0.25).unwrap().get(), 0.25);
+    assert_eq!(
+        ConstrainedF64::<Ratio>::new(0.0).unwrap_err(),
+        below("0", "0", false)
+    );
+    assert_eq!(
+        ConstrainedF32::<Gain>::new(16.5).unwrap_err(),
+        above("16.5", "16", true)
+    );
+    assert_eq!(ConstrainedF32::<Gain>::new(16.0).unwrap().get(), 16.0);
+
+    let value = ConstrainedF64::<Ratio>::deserialize(&parse("0.5")).unwrap();
+    assert_eq!(value.get(), 0.5);
+    assert_eq!(value, ConstrainedF64::<Ratio>::new(0.5).unwrap());
+}
+
+#[test]
+fn constrained_float_default() {
+    assert_eq!(ConstrainedF32::<Gain>::default().get(), 1.0);
+}
+
+#[test]
+fn constrained_float_nan() {
+    let error = ConstrainedF64::<Ratio>::deserialize(&f64::NAN.into()).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), below("NaN", "0", false))
+    );
+}
+
+#[test]
+fn constrained_float_wrong_type() {
+    let error = ConstrainedF64::<Ratio>::deserialize(&parse("true")).unwrap_err();
+    assert_eq!(wrong_type(error), "$");
+}
+
+#[test]
+fn range_not_object() {
+    let error = RangeI32::<-180, 180>::deserialize(&parse("[1, 2]")).unwrap_err();
+    assert_eq!(wrong_type(error), "$");
+}
+
+#[test]
+fn range_missing_bound() {
+    for (input, name) in &[(r#"{"max": 1}"#, "min"), (r#"{"min": 1}"#, "max")] {
+        let error = RangeI32::<-180, 180>::deserialize(&parse(input)).unwrap_err();
+        let (path, kind) = single(error);
+        assert_eq!(&path, name);
+        assert!(matches!(kind, ErrorKind::Missing));
+    }
+}
+
+#[test]
+fn range_wrong_type() {
+    let error =
+        RangeI32::<-180, 180>::deserialize(&parse(r#"{"min": "a", "max": 1}"#)).unwrap_err();
+    assert_eq!(wrong_type(error), "min");
+}
+
+#[test]
+fn range_bound_outside_limits() {
+    let error =
+        RangeI32::<-180, 180>::deserialize(&parse(r#"{"min": -181, "max": 0}"#)).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("min".to_owned(), below("-181", "-180", true))
+    );
+
+    let error =
+        RangeI32::<-180, 180>::deserialize(&parse(r#"{"min": 0, "max": 181}"#)).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("max".to_owned(), above("181", "180", true))
+    );
+}
+
+#[test]
+fn range_inverted() {
+    let error =
+        RangeI32::<-180, 180>::deserialize(&parse(r#"{"min": 10, "max": -10}"#)).unwrap_err();
+    assert_eq!(out_of_range(error), ("$".to_owned(), inverted("10", "-10")));
+
+    assert!(RangeI32::<-180, 180>::deserialize(&parse(r#"{"min": 10, "max": 10}"#)).is_ok());
+}
+
+#[test]
+fn range_new() {
+    let range = RangeI32::<-180, 180>::new(-10, 20).unwrap();
+    assert_eq!((range.min(), range.max()), (-10, 20));
+    assert_eq!(
+        RangeI32::<-180, 180>::new(-181, 0).unwrap_err(),
+        below("-181", "-180", true)
+    );
+    assert_eq!(
+        RangeI32::<-180, 180>::new(10, -10).unwrap_err(),
+        inverted("10", "-10")
+    );
+}
+
+#[test]
+fn range_default() {
+    let range = RangeI32::<-180, 180>::default();
+    assert_eq!((range.min(), range.max()), (-180, 180));
+}
+
+#[test]
+fn range_float() {
+    let error =
+        RangeF64::<Ratio>::deserialize(&parse(r#"{"min": 0.75, "max": 0.25}"#)).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("$".to_owned(), inverted("0.75", "0.25"))
+    );
+
+    let error = RangeF64::<Ratio>::deserialize(&parse(r#"{"min": 0.5, "max": 1}"#)).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("max".to_owned(), above("1", "1", false))
+    );
+}
+
+#[test]
+fn configurable_field_paths() {
+    let error = Settings::from_str(r#"{"threshold": 5, "hue": {"min": 0, "max": 0}}"#).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("threshold".to_owned(), below("5", "10", true))
+    );
+
+    let error =
+        Settings::from_str(r#"{"threshold": 50, "hue": {"min": 0, "max": 200}}"#).unwrap_err();
+    assert_eq!(
+        out_of_range(error),
+        ("hue.max".to_owned(), above("200", "180", true))
+    );
+
+    let error = Settings::from_str(r#"{"threshold": 50, "hue": 3}"#).unwrap_err();
+    assert_eq!(wrong_type(error), "hue");
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(
+        below("9", "10", true).to_string(),
+        "9 is below the inclusive minimum of 10"
+    );
+    assert_eq!(
+        above("1", "1", false).to_string(),
+        "1 is above the exclusive maximum of 1"
+    );
+    assert_eq!(
+        inverted("10", "-10").to_string(),
+        "range minimum 10 is greater than its maximum -10"
+    );
+}
+
+#[test]
+fn range_float_new() {
+    let range = RangeF64::<Ratio>::new(0.25, 0.75).unwrap();
+    assert_eq!((range.min(), range.max()), (0.25, 0.75));
+    assert_eq!(
+        range,
+        RangeF64::<Ratio>::deserialize(&parse(r#"{"min": 0.25, "max": 0.75}"#)).unwrap()
+    );
+
+    assert_eq!(
+        RangeF64::<Ratio>::new(0.0, 0.5).unwrap_err(),
+        below("0", "0", false)
+    );
+    assert_eq!(
+        RangeF64::<Ratio>::new(0.5, 1.0).unwrap_err(),
+        above("1", "1", false)
+    );
+    assert_eq!(
+        RangeF32::<Gain>::new(4.0, 2.0).unwrap_err(),
+        inverted("4", "2")
+    );
+}
+
+#[test]
+fn range_float_default() {
+    let range = RangeF32::<Gain>::default();
+    assert_eq!((range.min(), range.max()), (1.0, 16.0));
+}
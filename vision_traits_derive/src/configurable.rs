@@ -1,6 +1,6 @@
-use crate::field_name;
-use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields};
+use crate::fields::{self, NamedField};
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields};
 
 pub fn configurable_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
     let ident = &ast.ident;
@@ -12,57 +12,48 @@ pub fn configurable_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
             match struct_data.fields {
                 Fields::Named(ref fields) => {
                     if !fields.named.is_empty() {
-                        let name_map = fields
-                            .named
-                            .iter()
-                            .zip(fields.named.iter().map(&field_name))
-                            .collect::<Vec<_>>();
-
-                        let schema = name_map.iter().map(|(f, name)| {
-                            let ty = &f.ty;
-                            quote_spanned! {f.ident.span() =>
-                                map.insert(#name.to_owned(), <#ty as ::vision_traits::editable::Editable>::schema());
-                            }
-                        });
-
-                        let deserialize = name_map.iter().map(|(f, name)| {
-                            let ident = f.ident.as_ref();
-                            let ty = &f.ty;
-                            quote_spanned! {f.ident.span() =>
-                                #ident: <#ty as ::vision_traits::editable::Editable>::deserialize(
-                                    map.get(#name).ok_or_else(|| ::vision_traits::DeserializationError::MissingField(#name.to_owned()))?
-                                )
-                                .map_err(|x| ::vision_traits::DeserializationError::FieldDeserializationError(#name.to_owned(), x))?
-                            }
-                        });
+                        let fields = NamedField::named(&fields.named);
+                        let schema = fields::schema(&fields);
+                        let deserialize = fields::deserialize(quote! { Self }, &fields);
+                        let serialize = fields::serialize(&fields);
+                        let pattern = fields::pattern(quote! { Self }, &fields);
 
                         quote! {
-                            impl #impl_generics Configurable for #ident #ty_generics #where_clause {
+                            impl #impl_generics ::vision_traits::Configurable for #ident #ty_generics #where_clause {
                                 fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::SettingType> {
                                     let mut map = ::std::collections::HashMap::new();
-                                    #(#schema)*
+                                    #schema
                                     map
                                 }
 
-                                fn deserialize(input: &str) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
-                                    let json = ::vision_traits::json::parse(input).map_err(|_| ::vision_traits::DeserializationError::NotObject)?;
-                                    if let ::vision_traits::json::JsonValue::Object(ref map) = json {
-                                        Ok(Self { #(#deserialize),* })
+                                fn deserialize(input: &::vision_traits::json::JsonValue) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+                                    if let ::vision_traits::json::JsonValue::Object(ref map) = input {
+                                        #deserialize
                                     } else {
-                                        Err(::vision_traits::DeserializationError::NotObject)
+                                        Err(::vision_traits::DeserializationError::wrong_type("object"))
                                     }
                                 }
+
+                                fn serialize(&self) -> ::vision_traits::json::JsonValue {
+                                    let #pattern = self;
+                                    let mut json = ::vision_traits::json::JsonValue::new_object();
+                                    #serialize
+                                    json
+                                }
                             }
                         }
                     } else {
                         quote! {
-                            impl #impl_generics Configurable for #ident #ty_generics #where_clause {
+                            impl #impl_generics ::vision_traits::Configurable for #ident #ty_generics #where_clause {
                                 fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::SettingType> {
                                     ::std::collections::HashMap::new()
                                 }
-                                fn deserialize(input: &str) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+                                fn deserialize(_: &::vision_traits::json::JsonValue) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
                                     Ok(Self{})
                                 }
+                                fn serialize(&self) -> ::vision_traits::json::JsonValue {
+                                    ::vision_traits::json::JsonValue::new_object()
+                                }
                             }
                         }
                     }
@@ -70,13 +61,16 @@ pub fn configurable_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
 
                 Fields::Unit => {
                     quote! {
-                        impl #impl_generics Configurable for #ident #ty_generics #where_clause {
+                        impl #impl_generics ::vision_traits::Configurable for #ident #ty_generics #where_clause {
                             fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::SettingType> {
                                 ::std::collections::HashMap::new()
                             }
-                            fn deserialize(input: &str) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+                            fn deserialize(_: &::vision_traits::json::JsonValue) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
                                 Ok(Self)
                             }
+                            fn serialize(&self) -> ::vision_traits::json::JsonValue {
+                                ::vision_traits::json::JsonValue::new_object()
+                            }
                         }
                     }
                 }
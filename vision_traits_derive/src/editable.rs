@@ -0,0 +1,243 @@
+use crate::fields::{self, NamedField};
+use crate::variant_name;
+use quote::quote;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, token::Comma, DeriveInput, Error, Fields, LitStr,
+    Variant,
+};
+
+pub fn editable_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    let ident = &ast.ident;
+
+    match ast.data {
+        syn::Data::Enum(ref enum_data) => {
+            if enum_data.variants.is_empty() {
+                return Error::new(ident.span(), "Expected enum with at least one variant")
+                    .to_compile_error();
+            }
+
+            let body = if enum_data
+                .variants
+                .iter()
+                .all(|v| matches!(v.fields, Fields::Unit))
+            {
+                fieldless_enum(&enum_data.variants)
+            } else {
+                match tagged_enum(&enum_data.variants) {
+                    Ok(body) => body,
+                    Err(err) => return err.to_compile_error(),
+                }
+            };
+
+            let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+            quote! {
+                impl #impl_generics ::vision_traits::editable::Editable for #ident #ty_generics #where_clause {
+                    #body
+                }
+            }
+        }
+        syn::Data::Struct(ref struct_data) => {
+            let fields = match struct_data.fields {
+                Fields::Named(ref fields) => NamedField::named(&fields.named),
+                Fields::Unit => Vec::new(),
+                Fields::Unnamed(_) => {
+                    return Error::new(ident.span(), "Expected named struct, not tuple struct")
+                        .to_compile_error();
+                }
+            };
+            let body = object(&fields);
+
+            let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+            quote! {
+                impl #impl_generics ::vision_traits::editable::Editable for #ident #ty_generics #where_clause {
+                    #body
+                }
+            }
+        }
+        syn::Data::Union(_) => {
+            Error::new(ident.span(), "Expected struct or enum, not union").to_compile_error()
+        }
+    }
+}
+
+// Structs are serialized as objects holding their fields.
+fn object(fields: &[NamedField]) -> proc_macro2::TokenStream {
+    let schema = if fields.is_empty() {
+        quote! { ::vision_traits::json::JsonValue::new_object() }
+    } else {
+        let schema = fields::schema(fields);
+        quote! {{
+            let mut map = ::std::collections::HashMap::new();
+            #schema
+            ::vision_traits::schema::settings_to_json(&map)
+        }}
+    };
+    let deserialize = fields::deserialize(quote! { Self }, fields);
+    let pattern = fields::pattern(quote! { Self }, fields);
+    let serialize = fields::serialize(fields);
+
+    quote! {
+        fn schema() -> ::vision_traits::schema::SettingType {
+            let mut params = ::std::collections::HashMap::new();
+            params.insert("fields".to_owned(), #schema);
+
+            ::vision_traits::schema::SettingType {
+                name: "object".to_owned(),
+                params,
+            }
+        }
+
+        #[allow(unused_variables)]
+        fn deserialize(input: &::vision_traits::json::JsonValue) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+            if let ::vision_traits::json::JsonValue::Object(ref map) = input {
+                #deserialize
+            } else {
+                Err(::vision_traits::DeserializationError::wrong_type("object"))
+            }
+        }
+
+        fn serialize(&self) -> ::vision_traits::json::JsonValue {
+            let #pattern = self;
+            #[allow(unused_mut)]
+            let mut json = ::vision_traits::json::JsonValue::new_object();
+            #serialize
+            json
+        }
+    }
+}
+
+// Fieldless enums are serialized as the name of the variant.
+fn fieldless_enum(variants: &Punctuated<Variant, Comma>) -> proc_macro2::TokenStream {
+    let idents = variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let names = variants.iter().map(variant_name).collect::<Vec<_>>();
+
+    quote! {
+        fn schema() -> ::vision_traits::schema::SettingType {
+            let mut params = ::std::collections::HashMap::new();
+            params.insert("variants".to_owned(), ::vision_traits::json::JsonValue::from(vec![#(#names),*]));
+
+            ::vision_traits::schema::SettingType {
+                name: "enum".to_owned(),
+                params,
+            }
+        }
+
+        fn deserialize(input: &::vision_traits::json::JsonValue) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+            match input.as_str() {
+                #(Some(#names) => Ok(Self::#idents),)*
+                Some(other) => Err(::vision_traits::DeserializationError::invalid(format!("unknown variant `{}`, expected one of {:?}", other, [#(#names),*]))),
+                None => Err(::vision_traits::DeserializationError::wrong_type("variant name")),
+            }
+        }
+
+        fn serialize(&self) -> ::vision_traits::json::JsonValue {
+            match self {
+                #(Self::#idents => #names.into(),)*
+            }
+        }
+    }
+}
+
+// Enums with data are serialized as objects holding the name of the variant
+// under `type` alongside its fields. Single-field tuple variants store their
+// field under `value`.
+fn tagged_enum(variants: &Punctuated<Variant, Comma>) -> Result<proc_macro2::TokenStream, Error> {
+    let mut schema = Vec::new();
+    let mut deserialize = Vec::new();
+    let mut serialize = Vec::new();
+
+    for variant in variants {
+        let ident = &variant.ident;
+        let name = variant_name(variant);
+
+        let fields = match variant.fields {
+            Fields::Named(ref fields) => NamedField::named(&fields.named),
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                vec![NamedField::value(fields.unnamed.first().unwrap())]
+            }
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                return Err(Error::new(
+                    ident.span(),
+                    "Expected named or single-field tuple variant",
+                ));
+            }
+        };
+
+        let tag = fields.iter().find(|f| {
+            syn::parse2::<LitStr>(f.name.clone()).is_ok_and(|name| name.value() == "type")
+        });
+        if let Some(field) = tag {
+            return Err(Error::new(
+                field.field.span(),
+                "Field named `type` conflicts with the tag of the variant",
+            ));
+        }
+
+        let variant_schema = fields::schema(&fields);
+        if fields.is_empty() {
+            schema.push(quote! {
+                variants[#name] = ::vision_traits::json::JsonValue::new_object();
+            });
+        } else {
+            schema.push(quote! {
+                let mut map = ::std::collections::HashMap::new();
+                #variant_schema
+                variants[#name] = ::vision_traits::schema::settings_to_json(&map);
+            });
+        }
+
+        let variant_deserialize = fields::deserialize(quote! { Self::#ident }, &fields);
+        deserialize.push(quote! {
+            Some(Some(#name)) => #variant_deserialize,
+        });
+
+        let pattern = fields::pattern(quote! { Self::#ident }, &fields);
+        let variant_serialize = fields::serialize(&fields);
+        serialize.push(quote! {
+            #pattern => {
+                let mut json = ::vision_traits::json::JsonValue::new_object();
+                json["type"] = #name.into();
+                #variant_serialize
+                json
+            }
+        });
+    }
+
+    let names = variants.iter().map(variant_name);
+
+    Ok(quote! {
+        fn schema() -> ::vision_traits::schema::SettingType {
+            let mut variants = ::vision_traits::json::JsonValue::new_object();
+            #({ #schema })*
+
+            let mut params = ::std::collections::HashMap::new();
+            params.insert("tag".to_owned(), "type".into());
+            params.insert("variants".to_owned(), variants);
+
+            ::vision_traits::schema::SettingType {
+                name: "tagged_enum".to_owned(),
+                params,
+            }
+        }
+
+        fn deserialize(input: &::vision_traits::json::JsonValue) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+            let map = match input {
+                ::vision_traits::json::JsonValue::Object(ref map) => map,
+                _ => return Err(::vision_traits::DeserializationError::wrong_type("object")),
+            };
+            match map.get("type").map(|e| e.as_str()) {
+                #(#deserialize)*
+                Some(Some(other)) => Err(::vision_traits::DeserializationError::invalid(format!("unknown variant `{}`, expected one of {:?}", other, [#(#names),*])).in_field("type")),
+                Some(None) => Err(::vision_traits::DeserializationError::wrong_type("variant name").in_field("type")),
+                None => Err(::vision_traits::DeserializationError::missing("type")),
+            }
+        }
+
+        fn serialize(&self) -> ::vision_traits::json::JsonValue {
+            match self {
+                #(#serialize)*
+            }
+        }
+    })
+}
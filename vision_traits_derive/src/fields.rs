@@ -0,0 +1,141 @@
+use crate::{field_default, field_name};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Field, Ident, Index, Member};
+
+/// A field read from and written to a JSON object under `name`.
+pub struct NamedField<'a> {
+    pub field: &'a Field,
+    pub name: proc_macro2::TokenStream,
+    pub member: Member,
+    pub binding: Ident,
+}
+
+impl<'a> NamedField<'a> {
+    pub fn named(fields: impl IntoIterator<Item = &'a Field>) -> Vec<Self> {
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| Self {
+                field,
+                name: field_name(field),
+                member: Member::Named(field.ident.clone().unwrap()),
+                binding: format_ident!("__field{}", i),
+            })
+            .collect()
+    }
+
+    pub fn value(field: &'a Field) -> Self {
+        Self {
+            field,
+            name: quote! { "value" },
+            member: Member::Unnamed(Index::from(0)),
+            binding: format_ident!("__field0"),
+        }
+    }
+}
+
+/// Inserts the `SettingType` of every field into `map`.
+pub fn schema(fields: &[NamedField]) -> proc_macro2::TokenStream {
+    let schema = fields.iter().map(|NamedField { field, name, .. }| {
+        let ty = &field.ty;
+        if let Some(default) = field_default(field) {
+            quote_spanned! {field.span() =>
+                let mut setting = <#ty as ::vision_traits::editable::Editable>::schema();
+                setting.params.insert("default".to_owned(), #default);
+                map.insert(#name.to_owned(), setting);
+            }
+        } else {
+            quote_spanned! {field.span() =>
+                map.insert(#name.to_owned(), <#ty as ::vision_traits::editable::Editable>::schema());
+            }
+        }
+    });
+
+    quote! { #(#schema)* }
+}
+
+/// An expression reading every field from the `map` object into `path`,
+/// reporting the errors of all fields at once.
+pub fn deserialize(
+    path: proc_macro2::TokenStream,
+    fields: &[NamedField],
+) -> proc_macro2::TokenStream {
+    let read = fields.iter().map(
+        |NamedField {
+             field,
+             name,
+             binding,
+             ..
+         }| {
+            let ty = &field.ty;
+            let missing = match field_default(field) {
+                Some(default) => quote! {
+                    errors.check(
+                        <#ty as ::vision_traits::editable::Editable>::deserialize(&#default)
+                            .map_err(|e| e.in_field(#name)),
+                    )
+                },
+                None => quote! {
+                    match <#ty as ::vision_traits::editable::Editable>::missing() {
+                        Some(value) => Some(value),
+                        None => {
+                            errors.push(::vision_traits::DeserializationError::missing(#name));
+                            None
+                        }
+                    }
+                },
+            };
+            quote_spanned! {field.span() =>
+                let #binding: ::std::option::Option<#ty> = match map.get(#name) {
+                    Some(value) => errors.check(
+                        <#ty as ::vision_traits::editable::Editable>::deserialize(value)
+                            .map_err(|e| e.in_field(#name)),
+                    ),
+                    None => #missing,
+                };
+            }
+        },
+    );
+    let init = fields.iter().map(
+        |NamedField {
+             member, binding, ..
+         }| quote! { #member: #binding.unwrap() },
+    );
+
+    quote! {{
+        #[allow(unused_mut)]
+        let mut errors = ::vision_traits::ErrorCollector::new();
+        #(#read)*
+        errors.finish().map(|()| #path { #(#init),* })
+    }}
+}
+
+/// Writes every field, accessed through its binding, into the `json` object.
+pub fn serialize(fields: &[NamedField]) -> proc_macro2::TokenStream {
+    let serialize = fields.iter().map(
+        |NamedField {
+             field,
+             name,
+             binding,
+             ..
+         }| {
+            let ty = &field.ty;
+            quote_spanned! {field.span() =>
+                json[#name] = <#ty as ::vision_traits::editable::Editable>::serialize(#binding);
+            }
+        },
+    );
+
+    quote! { #(#serialize)* }
+}
+
+/// A pattern binding every field of `path` by reference.
+pub fn pattern(path: proc_macro2::TokenStream, fields: &[NamedField]) -> proc_macro2::TokenStream {
+    let bindings = fields.iter().map(
+        |NamedField {
+             member, binding, ..
+         }| quote! { #member: #binding },
+    );
+
+    quote! { #path { #(#bindings),* } }
+}
@@ -1,6 +1,9 @@
 use crate::field_name;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Lifetime, Type};
+use syn::{
+    spanned::Spanned, Attribute, Data, DeriveInput, Error, Field, Fields, GenericArgument,
+    GenericParam, Lifetime, PathArguments, Type,
+};
 
 pub fn input_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
     let ident = &ast.ident;
@@ -13,54 +16,68 @@ pub fn input_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 Fields::Named(ref fields) => {
                     if !fields.named.is_empty() {
                         let trait_lifetime = match lifetime_name(ast) {
-                            Ok(stream) => stream,
+                            Ok(lifetime) => lifetime,
                             Err(err) => return err,
                         };
 
+                        let mut errors = Vec::new();
                         let name_map = fields
                             .named
                             .iter()
-                            .zip(fields.named.iter().map(&field_name))
+                            .filter_map(|f| match input_field(f) {
+                                Ok((ty, kind)) => Some((f, field_name(f), ty, kind)),
+                                Err(err) => {
+                                    errors.push(err.to_compile_error());
+                                    None
+                                }
+                            })
                             .collect::<Vec<_>>();
+                        if !errors.is_empty() {
+                            return quote! { #(#errors)* };
+                        }
 
-                        let from_any_map = name_map.iter().map(|(f, name)| {
+                        let from_any_map = name_map.iter().map(|(f, name, ty, kind)| {
                             let ident = f.ident.as_ref();
-                            let ty = &f.ty;
-
-                            if let Type::Reference(ref ty) = ty {
-                                let ty = &ty.elem;
-                                quote_spanned! {f.ident.span() =>
-                                    #ident: map.get(#name).ok_or_else(|| ::vision_traits::DeserializationError::MissingField(#name.to_owned()))?.downcast_ref::<#ty>().ok_or_else(|| ::vision_traits::DeserializationError::TypeError(#name.to_owned()))?,
-                                }
-                            } else {
-                                quote_spanned! {f.ty.span() =>
-                                    #ident: compile_error!("Expected reference type")
-                                }
+                            let downcast = quote! {
+                                value.downcast_ref::<#ty>().ok_or_else(|| ::vision_traits::DeserializationError::wrong_type(<#ty as ::vision_traits::port::PortType>::name()).in_field(#name))?
+                            };
+                            match kind {
+                                InputKind::Required => quote_spanned! {f.ident.span() =>
+                                    #ident: {
+                                        let value = map.get(#name).ok_or_else(|| ::vision_traits::DeserializationError::missing(#name))?;
+                                        #downcast
+                                    }
+                                },
+                                InputKind::Optional => quote_spanned! {f.ident.span() =>
+                                    #ident: match map.get(#name) {
+                                        Some(value) => Some(#downcast),
+                                        None => None,
+                                    }
+                                },
+                                InputKind::Variadic => quote_spanned! {f.ident.span() =>
+                                    #ident: ::vision_traits::input::variadic::<#ty>(map, #name)?
+                                },
                             }
                         });
 
-                        let schema = name_map.iter().map(|(f, name)| {
-                            let ty = &f.ty;
-
-                            if let Type::Reference(ref ty) = ty {
-                                let ty = &ty.elem;
-                                quote_spanned! {f.ident.span() =>
-                                    map.insert(#name.to_owned(), ::vision_traits::Type { name: ::std::any::type_name::<#ty>().to_owned() });
-                                }
-                            } else {
-                                // compile_error already inserted above
-                                quote! {
-                                }
+                        let schema = name_map.iter().map(|(f, name, ty, kind)| {
+                            let constructor = match kind {
+                                InputKind::Required => quote! { required },
+                                InputKind::Optional => quote! { optional },
+                                InputKind::Variadic => quote! { variadic },
+                            };
+                            quote_spanned! {f.ident.span() =>
+                                map.insert(#name.to_owned(), ::vision_traits::schema::Type::#constructor::<#ty>());
                             }
                         });
 
                         quote! {
-                            impl #impl_generics Input<#trait_lifetime> for #ident #ty_generics #where_clause {
-                                fn from_any_map(map: &#trait_lifetime ::std::collections::HashMap<::std::string::String, &dyn ::std::any::Any>) -> ::std::result::Result<Self, ::vision_traits::DeserializationError>  {
-                                    Some(Self {#(#from_any_map),*})
+                            impl #impl_generics ::vision_traits::input::Input<#trait_lifetime> for #ident #ty_generics #where_clause {
+                                fn from_any_map(map: &#trait_lifetime ::std::collections::HashMap<::std::string::String, &dyn ::std::any::Any>) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
+                                    Ok(Self { #(#from_any_map),* })
                                 }
 
-                                fn schema() -> ::std::collections::HashMap<&::std::string::String, ::vision_traits::Type> {
+                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::Type> {
                                     let mut map = ::std::collections::HashMap::new();
                                     #(#schema)*
                                     map
@@ -69,12 +86,12 @@ pub fn input_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
                         }
                     } else {
                         quote! {
-                            impl #impl_generics Input<'_> for #ident #ty_generics #where_clause {
+                            impl #impl_generics ::vision_traits::input::Input<'_> for #ident #ty_generics #where_clause {
                                 fn from_any_map(_: &::std::collections::HashMap<::std::string::String, &dyn ::std::any::Any>) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
-                                    Some(Self{})
+                                    Ok(Self {})
                                 }
 
-                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::Type> {
+                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::Type> {
                                     ::std::collections::HashMap::new()
                                 }
                             }
@@ -84,12 +101,12 @@ pub fn input_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
 
                 Fields::Unit => {
                     quote! {
-                        impl #impl_generics Input<'_> for #ident #ty_generics #where_clause {
+                        impl #impl_generics ::vision_traits::input::Input<'_> for #ident #ty_generics #where_clause {
                             fn from_any_map(_: &::std::collections::HashMap<::std::string::String, &dyn ::std::any::Any>) -> ::std::result::Result<Self, ::vision_traits::DeserializationError> {
-                                Some(Self)
+                                Ok(Self)
                             }
 
-                            fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::Type> {
+                            fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::Type> {
                                 ::std::collections::HashMap::new()
                             }
                         }
@@ -107,33 +124,136 @@ pub fn input_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
     }
 }
 
-fn lifetime_name(ast: &DeriveInput) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Required,
+    Optional,
+    Variadic,
+}
+
+/// The referenced type of an input field and its kind, checking that only
+/// `Vec<&T>` fields are marked `#[variadic]`.
+fn input_field(field: &Field) -> Result<(&Type, InputKind), Error> {
+    let variadic_attrs = field
+        .attrs
+        .iter()
+        .filter(|x| x.path.is_ident("variadic"))
+        .collect::<Vec<&Attribute>>();
+
+    if variadic_attrs.len() > 1 {
+        return Err(Error::new(
+            variadic_attrs[1].span(),
+            "Multiple variadic attributes on one field",
+        ));
+    }
+    if let Some(attr) = variadic_attrs.first() {
+        if !attr.tokens.is_empty() {
+            return Err(Error::new(
+                attr.span(),
+                "Invalid variadic attribute format, expected: #[variadic]",
+            ));
+        }
+    }
+
+    match (input_type(&field.ty), variadic_attrs.is_empty()) {
+        (Some((_, InputKind::Variadic)), true) => Err(Error::new(
+            field.ty.span(),
+            "Expected #[variadic] on a Vec input",
+        )),
+        (Some((ty, InputKind::Variadic)), false) => Ok((ty, InputKind::Variadic)),
+        (Some(input), true) => Ok(input),
+        (_, false) => Err(Error::new(
+            field.ty.span(),
+            "Expected Vec of shared references for a #[variadic] input",
+        )),
+        (None, true) => Err(Error::new(
+            field.ty.span(),
+            "Expected shared reference type or an Option of one",
+        )),
+    }
+}
+
+/// The referenced type of an input type, and its kind. `Option<&T>` inputs
+/// are `None` when not connected, and `Vec<&T>` inputs read every connected
+/// slot of a variadic port.
+pub fn input_type(ty: &Type) -> Option<(&Type, InputKind)> {
+    match ty {
+        Type::Reference(ref reference) if reference.mutability.is_none() => {
+            Some((&reference.elem, InputKind::Required))
+        }
+        Type::Path(ref path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            let kind = if segment.ident == "Option" {
+                InputKind::Optional
+            } else if segment.ident == "Vec" {
+                InputKind::Variadic
+            } else {
+                return None;
+            };
+            match segment.arguments {
+                PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => {
+                    match args.args.first()? {
+                        GenericArgument::Type(Type::Reference(ref reference))
+                            if reference.mutability.is_none() =>
+                        {
+                            Some((&reference.elem, kind))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The lifetime of the input map, `'a` unless given with `#[input_lifetime]`.
+/// It must be declared by the struct.
+fn lifetime_name(ast: &DeriveInput) -> Result<Lifetime, proc_macro2::TokenStream> {
     let name_attrs = ast
         .attrs
         .iter()
         .filter(|x| x.path.is_ident("input_lifetime"))
         .collect::<Vec<&Attribute>>();
 
-    if name_attrs.is_empty() {
-        Ok(quote! { 'a })
+    let lifetime = if name_attrs.is_empty() {
+        Lifetime::new("'a", proc_macro2::Span::call_site())
     } else if name_attrs.len() == 1 {
         let name_attr = name_attrs.first().unwrap();
-        let lifetime = name_attr.parse_args::<Lifetime>();
-
-        if let Ok(ref lifetime) = lifetime {
-            Ok(quote! { #lifetime })
-        } else {
-            Err(Error::new(
-                name_attrs.first().span(),
-                "Invalid input_lifetime attribute format, expected: #[input_lifetime('a)]",
-            )
-            .to_compile_error())
+
+        match name_attr.parse_args::<Lifetime>() {
+            Ok(lifetime) => lifetime,
+            Err(_) => {
+                return Err(Error::new(
+                    name_attr.span(),
+                    "Invalid input_lifetime attribute format, expected: #[input_lifetime('a)]",
+                )
+                .to_compile_error())
+            }
         }
     } else {
-        Err(Error::new(
-            name_attrs.first().span(),
+        return Err(Error::new(
+            name_attrs[1].span(),
             "Multiple input_lifetime attributes on one struct",
         )
+        .to_compile_error());
+    };
+
+    let declared = ast.generics.params.iter().any(|param| match param {
+        GenericParam::Lifetime(ref def) => def.lifetime == lifetime,
+        _ => false,
+    });
+    if declared {
+        Ok(lifetime)
+    } else {
+        Err(Error::new(
+            ast.ident.span(),
+            format!(
+                "Expected a lifetime parameter {} for the input references",
+                lifetime
+            ),
+        )
         .to_compile_error())
     }
 }
@@ -1,20 +1,53 @@
 mod configurable;
+mod editable;
+mod fields;
 mod input;
+mod node;
 mod output;
+mod port_type;
+mod register;
 
 use configurable::configurable_impl;
+use editable::editable_impl;
 use input::input_impl;
+use node::vision_node_impl;
 use output::output_impl;
-use quote::quote;
-use syn::{parse_macro_input, spanned::Spanned, Attribute, DeriveInput, Error, Field, Lit, Meta};
+use port_type::port_type_impl;
+use quote::{quote, ToTokens};
+use register::register_node_impl;
+use syn::{
+    ext::IdentExt, parse_macro_input, spanned::Spanned, Attribute, AttributeArgs, DeriveInput,
+    Error, Expr, Field, ItemFn, ItemImpl, Lit, Meta, Variant,
+};
 
-#[proc_macro_derive(Configurable, attributes(name))]
+/// Fields may be renamed with `#[name = "..."]` and given a value to use when
+/// missing with `#[default]`, `#[default(expr)]` or `#[default = literal]`,
+/// where the literal is read as the JSON value it spells. Defaults are checked
+/// like any other value when used. `#[default = literal]` conflicts with the
+/// helper of `#[derive(Default)]`, so implement `Default` manually on structs
+/// that use it. Missing `Option` fields are `None` unless given a default.
+#[proc_macro_derive(Configurable, attributes(name, default))]
 pub fn configurable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(configurable_impl(&ast))
 }
 
-#[proc_macro_derive(Input, attributes(name, input_lifetime))]
+/// Structs are edited as nested objects. Fieldless enums are edited as one of
+/// their variant names, other enums as objects tagged with the variant name
+/// under `type`, which their fields may not be named. Variants may be renamed
+/// with `#[name = "..."]`, and fields take the same attributes as in
+/// `#[derive(Configurable)]`.
+#[proc_macro_derive(Editable, attributes(name, default))]
+pub fn editable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(editable_impl(&ast))
+}
+
+/// Fields are references into the node's input map, borrowed for `'a` or the
+/// lifetime given with `#[input_lifetime('b)]`. Fields of type `Option<&T>`
+/// are optional inputs, which are `None` when not connected, and fields of
+/// type `Vec<&T>` marked `#[variadic]` read the slots `name.0`, `name.1`, ...
+#[proc_macro_derive(Input, attributes(name, input_lifetime, variadic))]
 pub fn input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(input_impl(&ast))
@@ -26,38 +59,157 @@ pub fn output(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     proc_macro::TokenStream::from(output_impl(&ast))
 }
 
+/// The id must be given with `#[id = "..."]`, and the name, which defaults to
+/// the type's identifier, may be set with `#[name = "..."]` along with
+/// `#[description = "..."]`. Generic types are not supported, as every
+/// instantiation would share one id.
+#[proc_macro_derive(PortType, attributes(id, name, description))]
+pub fn port_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(port_type_impl(&ast))
+}
+
+#[proc_macro_attribute]
+pub fn register_node(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if !attr.is_empty() {
+        return Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            "register_node does not take arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let item = parse_macro_input!(item as ItemImpl);
+    proc_macro::TokenStream::from(register_node_impl(&item))
+}
+
+/// Turns a function taking `settings: &Settings` and any number of input
+/// references into a stateless node, e.g.
+/// `#[vision_node(name = "Blur")] fn blur(settings: &BlurSettings, image: &Image) -> Image`
+/// generates a `Blur` node with a `BlurInput` input struct. Inputs taken as
+/// `Option<&T>` are optional and inputs taken as `Vec<&T>` are variadic.
+/// Functions may return a `Result`, and `register` submits the node like
+/// `#[register_node]`.
+#[proc_macro_attribute]
+pub fn vision_node(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
+    let item = parse_macro_input!(item as ItemFn);
+    proc_macro::TokenStream::from(vision_node_impl(&args, &item))
+}
+
 fn field_name(field: &Field) -> proc_macro2::TokenStream {
-    let name_attrs = field
-        .attrs
+    name_attr(
+        &field.attrs,
+        field.ident.as_ref().unwrap().unraw().to_string(),
+        "field",
+    )
+}
+
+fn variant_name(variant: &Variant) -> proc_macro2::TokenStream {
+    name_attr(&variant.attrs, variant.ident.unraw().to_string(), "variant")
+}
+
+fn name_attr(attrs: &[Attribute], default: String, item: &str) -> proc_macro2::TokenStream {
+    string_attr(attrs, "name", item).unwrap_or_else(|| quote! { #default })
+}
+
+/// The string literal of a `#[key = "..."]` attribute, if there is one.
+fn string_attr(attrs: &[Attribute], key: &str, item: &str) -> Option<proc_macro2::TokenStream> {
+    let key_attrs = attrs
         .iter()
-        .filter(|x| x.path.is_ident("name"))
+        .filter(|x| x.path.is_ident(key))
         .collect::<Vec<&Attribute>>();
 
-    if name_attrs.is_empty() {
-        let name_string = field.ident.as_ref().unwrap().to_string();
-        quote! { #name_string }
-    } else if name_attrs.len() == 1 {
-        let name_attr = name_attrs.first().unwrap();
-
-        if let Ok(ref meta) = name_attr.parse_meta() {
-            if let Meta::NameValue(ref value) = meta {
-                if let Lit::Str(ref lit_str) = value.lit {
-                    let name_string = lit_str.value();
-                    return quote! { #name_string };
-                }
+    if key_attrs.is_empty() {
+        None
+    } else if key_attrs.len() == 1 {
+        let key_attr = key_attrs.first().unwrap();
+
+        if let Ok(Meta::NameValue(ref value)) = key_attr.parse_meta() {
+            if let Lit::Str(ref lit_str) = value.lit {
+                let string = lit_str.value();
+                return Some(quote! { #string });
             }
         }
 
-        Error::new(
-            name_attrs.first().span(),
-            "Invalid name attribute format, expected: #[name(\"foo\")]",
+        Some(
+            Error::new(
+                key_attrs.first().span(),
+                format!(
+                    "Invalid {} attribute format, expected: #[{} = \"foo\"]",
+                    key, key
+                ),
+            )
+            .to_compile_error(),
         )
-        .to_compile_error()
     } else {
-        Error::new(
-            name_attrs.first().span(),
-            "Multiple name attributes on one field",
+        Some(
+            Error::new(
+                key_attrs.first().span(),
+                format!("Multiple {} attributes on one {}", key, item),
+            )
+            .to_compile_error(),
+        )
+    }
+}
+
+/// The default of a field as a `JsonValue`, which is deserialized when the
+/// field is missing so that defaults are checked like any other value.
+fn field_default(field: &Field) -> Option<proc_macro2::TokenStream> {
+    let default_attrs = field
+        .attrs
+        .iter()
+        .filter(|x| x.path.is_ident("default"))
+        .collect::<Vec<&Attribute>>();
+
+    if default_attrs.is_empty() {
+        None
+    } else if default_attrs.len() == 1 {
+        let default_attr = default_attrs.first().unwrap();
+
+        let ty = &field.ty;
+        if default_attr.tokens.is_empty() {
+            return Some(quote! {
+                <#ty as ::vision_traits::editable::Editable>::serialize(&::std::default::Default::default())
+            });
+        }
+        if let Ok(expr) = default_attr.parse_args::<Expr>() {
+            return Some(quote! {
+                <#ty as ::vision_traits::editable::Editable>::serialize(&#expr)
+            });
+        }
+        if let Ok(Meta::NameValue(ref value)) = default_attr.parse_meta() {
+            let lit = match &value.lit {
+                // Unsuffixed integers would be `i32`, which may not fit.
+                Lit::Int(int) if int.suffix().is_empty() => match int.base10_parse::<u64>() {
+                    Ok(int) => proc_macro2::Literal::u64_suffixed(int).into_token_stream(),
+                    Err(error) => return Some(error.to_compile_error()),
+                },
+                lit => lit.into_token_stream(),
+            };
+            return Some(quote! { ::vision_traits::json::JsonValue::from(#lit) });
+        }
+
+        Some(
+            Error::new(
+                default_attrs.first().span(),
+                "Invalid default attribute format, expected: #[default], #[default(expr)] or #[default = literal]",
+            )
+            .to_compile_error(),
+        )
+    } else {
+        Some(
+            Error::new(
+                default_attrs.first().span(),
+                "Multiple default attributes on one field",
+            )
+            .to_compile_error(),
         )
-        .to_compile_error()
     }
 }
@@ -0,0 +1,207 @@
+use crate::input::{input_type, InputKind};
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, AttributeArgs, Error, FnArg, GenericArgument, ItemFn, Lifetime, Lit, Meta,
+    NestedMeta, Pat, PathArguments, ReturnType, Type,
+};
+
+pub fn vision_node_impl(args: &AttributeArgs, item: &ItemFn) -> proc_macro2::TokenStream {
+    let fn_ident = &item.sig.ident;
+    let vis = &item.vis;
+
+    if !item.sig.generics.params.is_empty() {
+        return Error::new(
+            item.sig.generics.span(),
+            "vision_node cannot be used on generic functions",
+        )
+        .to_compile_error();
+    }
+
+    let ident = format_ident!("{}", camel_case(&fn_ident.to_string()));
+    let input_ident = format_ident!("{}Input", ident);
+
+    let mut name = ident.to_string();
+    let mut register = false;
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("name") => {
+                if let Lit::Str(ref lit_str) = value.lit {
+                    name = lit_str.value();
+                    continue;
+                }
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("register") => {
+                register = true;
+                continue;
+            }
+            _ => {}
+        }
+        return Error::new(
+            arg.span(),
+            "Invalid vision_node attribute format, expected: #[vision_node(name = \"foo\", register)]",
+        )
+        .to_compile_error();
+    }
+
+    let lifetime = Lifetime::new("'a", proc_macro2::Span::call_site());
+    let mut settings = None;
+    let mut inputs = Vec::new();
+    let mut call_args = Vec::new();
+
+    for arg in &item.sig.inputs {
+        let arg = match arg {
+            FnArg::Typed(arg) => arg,
+            FnArg::Receiver(receiver) => {
+                return Error::new(receiver.span(), "vision_node cannot be used on methods")
+                    .to_compile_error();
+            }
+        };
+        let arg_ident = match *arg.pat {
+            Pat::Ident(ref pat) => &pat.ident,
+            _ => {
+                return Error::new(arg.pat.span(), "Expected identifier pattern")
+                    .to_compile_error();
+            }
+        };
+        if arg_ident == "settings" {
+            match *arg.ty {
+                Type::Reference(ref reference) if reference.mutability.is_none() => {
+                    settings = Some(&reference.elem);
+                }
+                _ => {
+                    return Error::new(arg.ty.span(), "Expected shared reference type")
+                        .to_compile_error();
+                }
+            }
+            call_args.push(quote! { &self.settings });
+        } else {
+            let field = match input_type(&arg.ty) {
+                Some((ty, InputKind::Required)) => quote! { #vis #arg_ident: &#lifetime #ty },
+                Some((ty, InputKind::Optional)) => {
+                    quote! { #vis #arg_ident: ::std::option::Option<&#lifetime #ty> }
+                }
+                Some((ty, InputKind::Variadic)) => {
+                    quote! { #[variadic] #vis #arg_ident: ::std::vec::Vec<&#lifetime #ty> }
+                }
+                None => {
+                    return Error::new(
+                        arg.ty.span(),
+                        "Expected shared reference type, or an Option or Vec of them",
+                    )
+                    .to_compile_error();
+                }
+            };
+            inputs.push(field);
+            call_args.push(quote! { input.#arg_ident });
+        }
+    }
+
+    let (settings_ty, settings_attr) = match settings {
+        Some(ty) => (quote! { #ty }, quote! {}),
+        None => (quote! { () }, quote! { #[allow(dead_code)] }),
+    };
+
+    let (output_ty, fallible) = match item.sig.output {
+        ReturnType::Default => (None, false),
+        ReturnType::Type(_, ref ty) => match result_ok_type(ty) {
+            Some(ty) => (Some(ty), true),
+            None => (Some(&**ty), false),
+        },
+    };
+    let output_ty = match output_ty {
+        Some(Type::Tuple(ref tuple)) if tuple.elems.is_empty() => None,
+        other => other,
+    };
+
+    let call = if fallible {
+        quote! { #fn_ident(#(#call_args),*)? }
+    } else {
+        quote! { #fn_ident(#(#call_args),*) }
+    };
+
+    let (o, process) = match output_ty {
+        Some(ty) => (
+            quote! { ::vision_traits::output::OutputSingular<#ty> },
+            quote! { Ok(::vision_traits::output::OutputSingular::from(#call)) },
+        ),
+        None => (quote! { () }, quote! { #call; Ok(()) }),
+    };
+
+    let (i, input_struct) = if inputs.is_empty() {
+        (quote! { () }, quote! {})
+    } else {
+        (
+            quote! { #input_ident<'a> },
+            quote! {
+                #[derive(::vision_traits::Input)]
+                #vis struct #input_ident<#lifetime> {
+                    #(#inputs),*
+                }
+            },
+        )
+    };
+
+    let registration = if register {
+        quote! {
+            ::vision_traits::inventory::submit! {
+                ::vision_traits::registry::NodeFactory::new::<#ident>()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #item
+
+        #vis struct #ident {
+            #settings_attr
+            settings: #settings_ty,
+        }
+
+        #input_struct
+
+        impl ::vision_traits::Node for #ident {
+            const NAME: &'static str = #name;
+
+            type S = #settings_ty;
+            type I<'a> = #i;
+            type O = #o;
+
+            fn make(settings: Self::S) -> ::vision_traits::DynErrResult<Self> {
+                Ok(Self { settings })
+            }
+
+            #[allow(unused_variables)]
+            fn process(&mut self, input: Self::I<'_>) -> ::vision_traits::DynErrResult<Self::O> {
+                #process
+            }
+        }
+
+        #registration
+    }
+}
+
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(ref path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Result" && segment.ident != "DynErrResult" {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref args) => match args.args.first()? {
+            GenericArgument::Type(ref ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect()
+}
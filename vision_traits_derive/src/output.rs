@@ -29,12 +29,12 @@ pub fn output_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
                             let ty = &f.ty;
 
                             quote_spanned! {f.ident.span() =>
-                                map.insert(#name.to_owned(), ::vision_traits::Type { name: ::std::any::type_name::<#ty>().to_owned() });
+                                map.insert(#name.to_owned(), ::vision_traits::schema::Type::required::<#ty>());
                             }
                         });
 
                         quote! {
-                            impl #impl_generics Output for #ident #ty_generics #where_clause {
+                            impl #impl_generics ::vision_traits::output::Output for #ident #ty_generics #where_clause {
                                 fn to_any_map(self) -> ::std::collections::HashMap<::std::string::String, ::std::boxed::Box<dyn ::std::any::Any>> {
                                     let mut map = ::std::collections::HashMap::new();
                                     #(#to_any_map)*
@@ -45,7 +45,7 @@ pub fn output_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
                                 //     Self { #(#from_any_map),* }.into()
                                 // }
 
-                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::Type> {
+                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::Type> {
                                     let mut map = ::std::collections::HashMap::new();
                                     #(#schema)*
                                     map
@@ -54,12 +54,12 @@ pub fn output_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
                         }
                     } else {
                         quote! {
-                            impl #impl_generics Output for #ident #ty_generics #where_clause {
+                            impl #impl_generics ::vision_traits::output::Output for #ident #ty_generics #where_clause {
                                 fn to_any_map(self) -> ::std::collections::HashMap<::std::string::String, ::std::boxed::Box<dyn ::std::any::Any>> {
                                     ::std::collections::HashMap::new()
                                 }
 
-                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::Type> {
+                                fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::Type> {
                                     ::std::collections::HashMap::new()
                                 }
                             }
@@ -69,7 +69,7 @@ pub fn output_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
 
                 Fields::Unit => {
                     quote! {
-                        impl #impl_generics Output for #ident #ty_generics #where_clause {
+                        impl #impl_generics ::vision_traits::output::Output for #ident #ty_generics #where_clause {
                             // fn from_any_map(any_map: &::std::collections::HashMap<::std::string::String, ::std::rc::Rc<dyn ::std::any::Any>>) -> Option<Self> {
                             //     Self.into()
                             // }
@@ -78,7 +78,7 @@ pub fn output_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
                                 ::std::collections::HashMap::new()
                             }
 
-                            fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::Type> {
+                            fn schema() -> ::std::collections::HashMap<::std::string::String, ::vision_traits::schema::Type> {
                                 ::std::collections::HashMap::new()
                             }
                         }
@@ -0,0 +1,43 @@
+use crate::{name_attr, string_attr};
+use quote::quote;
+use syn::{spanned::Spanned, DeriveInput, Error};
+
+pub fn port_type_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    let ident = &ast.ident;
+    if !ast.generics.params.is_empty() {
+        return Error::new(
+            ast.generics.span(),
+            "PortType cannot be derived for generic types",
+        )
+        .to_compile_error();
+    }
+
+    let id = match string_attr(&ast.attrs, "id", "type") {
+        Some(id) => id,
+        None => {
+            return Error::new(ident.span(), "Expected an #[id = \"...\"] attribute")
+                .to_compile_error();
+        }
+    };
+    let name = name_attr(&ast.attrs, ident.to_string(), "type");
+    let description = match string_attr(&ast.attrs, "description", "type") {
+        Some(description) => quote! { ::std::option::Option::Some(#description.to_owned()) },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    quote! {
+        impl ::vision_traits::port::PortType for #ident {
+            fn id() -> ::std::string::String {
+                #id.to_owned()
+            }
+
+            fn name() -> ::std::string::String {
+                #name.to_owned()
+            }
+
+            fn description() -> ::std::option::Option<::std::string::String> {
+                #description
+            }
+        }
+    }
+}
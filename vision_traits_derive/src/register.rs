@@ -0,0 +1,27 @@
+use quote::quote;
+use syn::{spanned::Spanned, Error, ItemImpl};
+
+pub fn register_node_impl(item: &ItemImpl) -> proc_macro2::TokenStream {
+    if !item.generics.params.is_empty() {
+        return Error::new(
+            item.generics.span(),
+            "register_node cannot be used on generic impls",
+        )
+        .to_compile_error();
+    }
+
+    match item.trait_ {
+        Some((None, ref path, _)) if path.segments.last().is_some_and(|s| s.ident == "Node") => {
+            let ty = &item.self_ty;
+
+            quote! {
+                #item
+
+                ::vision_traits::inventory::submit! {
+                    ::vision_traits::registry::NodeFactory::new::<#ty>()
+                }
+            }
+        }
+        _ => Error::new(item.span(), "Expected impl Node block").to_compile_error(),
+    }
+}